@@ -0,0 +1,88 @@
+use serde::{Deserialize, Serialize};
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+/// A puzzle answer. Most are numbers, some days spell out a word.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(u64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Number(n) => write!(f, "{}", n),
+            Self::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+/// Anything that reads as a number is one.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(s.parse()
+            .map_or_else(|_| Self::Text(s.to_owned()), Self::Number))
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(item: u32) -> Self {
+        Self::Number(item.into())
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(item: u64) -> Self {
+        Self::Number(item)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(item: usize) -> Self {
+        Self::Number(item as u64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(item: String) -> Self {
+        Self::Text(item)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(item: &str) -> Self {
+        Self::Text(item.to_owned())
+    }
+}
+
+impl PartialEq<u64> for Answer {
+    fn eq(&self, other: &u64) -> bool {
+        matches!(self, Self::Number(n) if n == other)
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        matches!(self, Self::Text(s) if s == other)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn number_displays() {
+        assert_eq!(Answer::from(24000u32).to_string(), "24000");
+    }
+
+    #[test]
+    fn text_displays() {
+        assert_eq!(Answer::from("CMZ").to_string(), "CMZ");
+    }
+}
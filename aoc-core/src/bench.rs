@@ -0,0 +1,49 @@
+//! Criterion helpers shared by every day's `benches/`.
+
+use crate::{input, normalize, AocError, Solution};
+use criterion::Criterion;
+use std::hint::black_box;
+
+pub use criterion;
+
+/// The real `input.txt` of `solution`, normalized.
+fn real_input<S: Solution + Sync>(solution: &S) -> String {
+    let source = input::resolve(solution, None).unwrap_or_else(|e| panic!("{}", e));
+    let input = input::read(&source).unwrap_or_else(|e| panic!("{}", e));
+
+    normalize(&input).into_owned()
+}
+
+/// Benchmark parsing, part 1 and part 2 of `solution` separately, all
+/// on its real `input.txt`.
+pub fn solution<S: Solution + Sync>(c: &mut Criterion, solution: &S) {
+    let input = real_input(solution);
+    let parsed = solution
+        .parse(&input)
+        .unwrap_or_else(|e| panic!("{}", e.render(Solution::name(solution))));
+
+    let mut group = c.benchmark_group(Solution::name(solution));
+
+    group.bench_function("parse", |b| b.iter(|| solution.parse(black_box(&input))));
+    group.bench_function("part1", |b| b.iter(|| solution.part1(black_box(&parsed))));
+    group.bench_function("part2", |b| b.iter(|| solution.part2(black_box(&parsed))));
+
+    group.finish();
+}
+
+/// Benchmark a day's `&[u8]` parser next to the `parse` benchmark from
+/// [`solution`].
+pub fn bytes<S, P>(c: &mut Criterion, solution: &S, parse: P)
+where
+    S: Solution + Sync,
+    P: for<'a> Fn(&'a [u8]) -> Result<S::Input<'a>, AocError>,
+{
+    let input = real_input(solution);
+    let mut group = c.benchmark_group(Solution::name(solution));
+
+    group.bench_function("parse_bytes", |b| {
+        b.iter(|| parse(black_box(input.as_bytes())))
+    });
+
+    group.finish();
+}
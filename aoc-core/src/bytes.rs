@@ -0,0 +1,152 @@
+//! Support for the `&[u8]` parsers some days offer next to their
+//! [`crate::Span`] based ones.
+
+use crate::{AocError, Diagnostic, Location};
+use nom::{character::complete::multispace0, error::Error, IResult};
+
+/// Byte level counterpart of [`crate::finish`]: run `parser` over all of
+/// `input`, allowing nothing but trailing whitespace after it.
+///
+/// Byte parsers trade `context` labels for speed, so errors name the
+/// nom combinator that failed instead.
+pub fn finish<'a, O, P>(input: &'a [u8], parser: P) -> Result<O, AocError>
+where
+    P: FnOnce(&'a [u8]) -> IResult<&'a [u8], O>,
+{
+    let error = |rest: &[u8], message: String| {
+        AocError::Parse(Diagnostic {
+            message,
+            location: Some(Location::at(input, input.len() - rest.len())),
+        })
+    };
+
+    let (rest, output) = match parser(input) {
+        Ok(ok) => ok,
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => {
+            return Err(error(
+                e.input,
+                format!("unexpected input ({})", e.code.description()),
+            ))
+        }
+        Err(nom::Err::Incomplete(_)) => {
+            return Err(AocError::Parse(Diagnostic {
+                message: "unexpected end of input".to_owned(),
+                location: None,
+            }))
+        }
+    };
+
+    let (rest, _) = multispace0::<_, Error<&[u8]>>(rest).expect("multispace0 never fails");
+
+    if rest.is_empty() {
+        Ok(output)
+    } else {
+        Err(error(
+            rest,
+            "unexpected input, parsing stopped here".to_owned(),
+        ))
+    }
+}
+
+/// Generate tests holding a day's byte level `parse` to its `&str`
+/// parser `$parse`. They must agree on the example and on the real
+/// `input.txt`, which has to exist, and reject the same inputs: each of
+/// `$malformed`, and every single byte deletion or substitution of the
+/// example.
+#[macro_export]
+macro_rules! bytes_agree {
+    ($parse:path; $($malformed:expr),+ $(,)?) => {
+        const EXAMPLE: &str = include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/fixtures/example1/input.txt"
+        ));
+
+        #[test]
+        fn agrees_with_str_parser() {
+            let path = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+            let real = std::fs::read_to_string(path)
+                .unwrap_or_else(|e| panic!("cannot read {}: {}", path, e));
+
+            for input in [EXAMPLE, &real] {
+                assert_eq!(
+                    parse(input.as_bytes()).unwrap(),
+                    $parse(input).unwrap()
+                );
+            }
+        }
+
+        #[test]
+        fn rejects_what_str_parser_rejects() {
+            for input in [$($malformed),+] {
+                assert!(
+                    matches!(parse(input.as_bytes()), Err($crate::AocError::Parse(_))),
+                    "bytes parser accepted {:?}",
+                    input
+                );
+                assert!(
+                    matches!($parse(input), Err($crate::AocError::Parse(_))),
+                    "str parser accepted {:?}",
+                    input
+                );
+            }
+
+            for i in 0..EXAMPLE.len() {
+                let mutants = [
+                    format!("{}{}", &EXAMPLE[..i], &EXAMPLE[i + 1..]),
+                    format!("{}x{}", &EXAMPLE[..i], &EXAMPLE[i + 1..]),
+                    format!("{}0{}", &EXAMPLE[..i], &EXAMPLE[i + 1..]),
+                ];
+
+                for input in &mutants {
+                    let bytes = parse(input.as_bytes()).map_err(|e| e.kind());
+                    let text = $parse(input).map_err(|e| e.kind());
+
+                    assert_eq!(bytes, text, "parsers disagree on {:?}", input);
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use nom::{
+        character::complete::{self, newline},
+        multi::separated_list1,
+    };
+
+    fn numbers(input: &[u8]) -> IResult<&[u8], Vec<u32>> {
+        separated_list1(newline, complete::u32)(input)
+    }
+
+    #[test]
+    fn finish_works() {
+        assert_eq!(finish(b"1\n2\n\n", numbers).unwrap(), vec![1, 2]);
+
+        let err = finish(b"1\n2\nthree\n", numbers).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "parse error: unexpected input, parsing stopped here at 3:1"
+        );
+        assert!(err.render("input.txt").contains("3 | three\n"));
+    }
+
+    #[test]
+    fn errors_are_located() {
+        let err = finish(b"12\nx3\n", |input| {
+            separated_list1(newline, complete::u32)(input).and_then(|(rest, n)| {
+                let (rest, _) = newline(rest)?;
+                let (rest, m) = complete::u32(rest)?;
+                Ok((rest, (n, m)))
+            })
+        })
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "parse error: unexpected input (Digit) at 2:1"
+        );
+    }
+}
@@ -0,0 +1,179 @@
+use crate::output::{Format, Record};
+use crate::{input, AocError, Dump, Options, Part, Run, Runner};
+use clap::{CommandFactory, FromArgMatches, Parser};
+use std::path::PathBuf;
+use std::process;
+
+#[derive(Parser, Debug)]
+#[command(author = "Felipe Balbi <felipe@balbi.sh>", version = "0.1.0")]
+pub struct Cli {
+    #[arg(short = '1', long = "part1", help = "Run part 1.")]
+    pub part1: bool,
+
+    #[arg(short = '2', long = "part2", help = "Run part 2.")]
+    pub part2: bool,
+
+    #[arg(
+        short,
+        long,
+        value_name = "PATH",
+        help = "Read the puzzle input from PATH, or `-` for stdin."
+    )]
+    pub input: Option<PathBuf>,
+
+    #[arg(
+        long,
+        help = "Keep parsing past malformed lines and report all of them."
+    )]
+    pub recover: bool,
+
+    #[arg(long, help = "Ignore input left over after parsing stops.")]
+    pub lenient: bool,
+
+    #[arg(long, help = "Print how long parsing and each part took.")]
+    pub time: bool,
+
+    #[arg(long, value_enum, default_value_t, help = "How to print answers.")]
+    pub format: Format,
+
+    #[arg(
+        long,
+        value_enum,
+        value_name = "FORMAT",
+        help = "Print the parsed input instead of solving it."
+    )]
+    pub dump_parsed: Option<Dump>,
+
+    #[arg(
+        long,
+        conflicts_with_all = ["recover", "lenient", "dump_parsed"],
+        help = "Solve while reading, without holding the input in memory."
+    )]
+    pub stream: bool,
+}
+
+impl Cli {
+    /// Parse the process arguments, naming the command after `solution`.
+    pub fn parse_for(solution: &dyn Runner) -> Self {
+        let matches = Self::command()
+            .name(solution.name())
+            .about(format!(
+                "Advent of Code {} Day {}",
+                solution.year(),
+                solution.day()
+            ))
+            .get_matches();
+
+        Self::from_arg_matches(&matches).unwrap_or_else(|e| e.exit())
+    }
+
+    pub fn parts(&self) -> Vec<Part> {
+        let mut parts = vec![];
+
+        if self.part1 {
+            parts.push(Part::One);
+        }
+
+        if self.part2 {
+            parts.push(Part::Two);
+        }
+
+        parts
+    }
+
+    pub fn options(&self) -> Options {
+        Options {
+            recover: self.recover,
+            lenient: self.lenient,
+        }
+    }
+
+    /// Print the outcome of running `solution` in the chosen format.
+    /// Errors go to stderr as text, but are part of the record otherwise.
+    pub fn report(&self, solution: &dyn Runner, result: &Result<Run, AocError>, file: &str) {
+        match self.format {
+            Format::Text => self.report_text(result, file),
+            Format::Json => println!("{}", Record::new(solution, result).to_json()),
+            Format::Csv => {
+                println!("{}", crate::output::CSV_HEADER);
+                for row in Record::new(solution, result).to_csv() {
+                    println!("{}", row);
+                }
+            }
+        }
+    }
+
+    fn report_text(&self, result: &Result<Run, AocError>, file: &str) {
+        let run = match result {
+            Ok(run) => run,
+            Err(e) => return eprintln!("{}", e.render(file)),
+        };
+
+        for solved in &run.parts {
+            println!("Part {}: {}", solved.part, solved.answer);
+        }
+
+        if self.time {
+            println!();
+            println!("{:<8}{:>12.2?}", "parse", run.parse);
+            for solved in &run.parts {
+                println!(
+                    "{:<8}{:>12.2?}",
+                    format!("part {}", solved.part),
+                    solved.elapsed
+                );
+            }
+        }
+    }
+}
+
+/// Entry point shared by every day's binary.
+pub fn main(solution: &dyn Runner) {
+    let cli = Cli::parse_for(solution);
+
+    let source = match input::resolve(solution, cli.input.as_deref()) {
+        Ok(source) => source,
+        Err(e) => return finish(&cli, solution, &Err(e.into()), solution.name()),
+    };
+    let file = source.to_string();
+
+    if cli.stream {
+        let result = input::open(&source)
+            .map_err(AocError::from)
+            .and_then(|mut reader| {
+                solution
+                    .stream(&source, &mut reader, &cli.parts())
+                    .unwrap_or_else(|| {
+                        eprintln!("error: {} cannot stream its input", solution.name());
+                        process::exit(2);
+                    })
+            });
+
+        return finish(&cli, solution, &result, &file);
+    }
+
+    let input = input::read(&source).map_err(AocError::from);
+
+    if let Some(format) = cli.dump_parsed {
+        match input.and_then(|input| solution.dump(&input, &cli.options(), format)) {
+            Ok(dump) => println!("{}", dump),
+            Err(e) => {
+                eprintln!("{}", e.render(&file));
+                process::exit(e.exit_code());
+            }
+        }
+        return;
+    }
+
+    let result = input.and_then(|input| solution.run(&input, &cli.parts(), &cli.options()));
+
+    finish(&cli, solution, &result, &file);
+}
+
+fn finish(cli: &Cli, solution: &dyn Runner, result: &Result<Run, AocError>, file: &str) {
+    cli.report(solution, result, file);
+
+    if let Err(e) = result {
+        process::exit(e.exit_code());
+    }
+}
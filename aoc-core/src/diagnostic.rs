@@ -0,0 +1,184 @@
+use nom::error::{ErrorKind, VerboseError, VerboseErrorKind};
+use nom_locate::LocatedSpan;
+use std::fmt;
+
+/// Input type for every day's parsers. It tracks line and column, so a
+/// failure can be pointed at in the original input.
+pub type Span<'a> = LocatedSpan<&'a str>;
+
+pub type ParseResult<'a, O> = nom::IResult<Span<'a>, O, VerboseError<Span<'a>>>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Location {
+    pub line: u32,
+    pub column: usize,
+    pub source_line: String,
+}
+
+impl Location {
+    pub fn of(span: &Span) -> Self {
+        let line = String::from_utf8_lossy(span.get_line_beginning());
+
+        Self {
+            line: span.location_line(),
+            column: span.get_utf8_column(),
+            source_line: line.trim_end_matches('\r').to_owned(),
+        }
+    }
+
+    /// Where byte `offset` of `input` is, for parsers that work on
+    /// `&[u8]` and so have no [`Span`] to ask.
+    pub fn at(input: &[u8], offset: usize) -> Self {
+        let (before, after) = input.split_at(offset);
+        let start = before
+            .iter()
+            .rposition(|&b| b == b'\n')
+            .map_or(0, |i| i + 1);
+        let end = after
+            .iter()
+            .position(|&b| b == b'\n')
+            .map_or(input.len(), |i| offset + i);
+        let line = String::from_utf8_lossy(&input[start..end]);
+
+        Self {
+            line: before.iter().filter(|&&b| b == b'\n').count() as u32 + 1,
+            column: String::from_utf8_lossy(&before[start..]).chars().count() + 1,
+            source_line: line.trim_end_matches('\r').to_owned(),
+        }
+    }
+}
+
+/// A parse failure, rendered the way rustc renders its errors.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub message: String,
+    pub location: Option<Location>,
+}
+
+impl Diagnostic {
+    pub fn render(&self, file: &str) -> String {
+        render("error", &self.message, self.location.as_ref(), file)
+    }
+}
+
+/// A `label: message` headline followed by where in `file` it applies,
+/// with a caret under the column.
+pub(crate) fn render(
+    label: &str,
+    message: &str,
+    location: Option<&Location>,
+    file: &str,
+) -> String {
+    let location = match location {
+        Some(location) => location,
+        None => return format!("{}: {}\n --> {}", label, message, file),
+    };
+
+    let number = location.line.to_string();
+    let pad = " ".repeat(number.len());
+    let caret = " ".repeat(location.column.saturating_sub(1));
+
+    format!(
+        "{label}: {message}\n{pad}--> {file}:{line}:{column}\n{pad} |\n{number} | {text}\n{pad} | {caret}^",
+        line = location.line,
+        column = location.column,
+        text = location.source_line,
+    )
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.location {
+            Some(location) => write!(
+                f,
+                "{} at {}:{}",
+                self.message, location.line, location.column
+            ),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl From<VerboseError<Span<'_>>> for Diagnostic {
+    /// The caret goes where the innermost parser gave up, while the
+    /// message comes from the innermost `context` label around it.
+    fn from(item: VerboseError<Span<'_>>) -> Self {
+        let location = item.errors.first().map(|(span, _)| Location::of(span));
+
+        let label = item.errors.iter().find_map(|(_, kind)| match kind {
+            VerboseErrorKind::Context(label) => Some(format!("expected {}", label)),
+            _ => None,
+        });
+
+        let message = label.unwrap_or_else(|| match item.errors.first() {
+            Some((_, VerboseErrorKind::Char(c))) => format!("expected `{}`", c),
+            Some((_, VerboseErrorKind::Nom(ErrorKind::Eof))) => {
+                "unexpected trailing input".to_owned()
+            }
+            Some((_, VerboseErrorKind::Nom(kind))) => {
+                format!("unexpected input ({})", kind.description())
+            }
+            _ => "unexpected input".to_owned(),
+        });
+
+        Self { message, location }
+    }
+}
+
+impl From<nom::Err<VerboseError<Span<'_>>>> for Diagnostic {
+    fn from(item: nom::Err<VerboseError<Span<'_>>>) -> Self {
+        match item {
+            nom::Err::Incomplete(_) => Self {
+                message: "unexpected end of input".to_owned(),
+                location: None,
+            },
+            nom::Err::Error(e) | nom::Err::Failure(e) => e.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use nom::{bytes::complete::tag, character::complete, error::context, sequence::tuple};
+
+    fn parse_move(input: Span<'_>) -> ParseResult<'_, (u32, u32)> {
+        let (input, (_, a, _, b)) = context(
+            "`move N to M`",
+            tuple((tag("move "), complete::u32, tag(" to "), complete::u32)),
+        )(input)?;
+
+        Ok((input, (a, b)))
+    }
+
+    #[test]
+    fn context_labels_message() {
+        let input = Span::new("move 1 to 2\nmove x to 3\n");
+        let err = tuple((parse_move, complete::newline, parse_move))(input).unwrap_err();
+        let diagnostic = Diagnostic::from(err);
+
+        assert_eq!(diagnostic.to_string(), "expected `move N to M` at 2:6");
+        assert_eq!(diagnostic.location.unwrap().source_line, "move x to 3");
+    }
+
+    #[test]
+    fn render_works() {
+        let diagnostic = Diagnostic {
+            message: "expected `move N from A to B`".to_owned(),
+            location: Some(Location {
+                line: 12,
+                column: 6,
+                source_line: "move x from 1 to 2".to_owned(),
+            }),
+        };
+
+        assert_eq!(
+            diagnostic.render("input.txt"),
+            "error: expected `move N from A to B`
+  --> input.txt:12:6
+   |
+12 | move x from 1 to 2
+   |      ^"
+        );
+    }
+}
@@ -0,0 +1,54 @@
+use clap::ValueEnum;
+use serde::Serialize;
+use std::fmt;
+
+/// How `--dump-parsed` prints a day's parsed input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Dump {
+    Json,
+    Ron,
+    /// Rust's pretty `{:#?}` output.
+    Debug,
+}
+
+impl Dump {
+    pub fn render<T: Serialize + fmt::Debug>(self, value: &T) -> String {
+        match self {
+            Self::Json => serde_json::to_string_pretty(value).expect("parsed input serializes"),
+            Self::Ron => ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default())
+                .expect("parsed input serializes"),
+            Self::Debug => format!("{:#?}", value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Debug, Serialize)]
+    struct Move {
+        amount: usize,
+        from: usize,
+    }
+
+    #[test]
+    fn json_works() {
+        let moves = vec![Move { amount: 1, from: 2 }];
+
+        assert_eq!(
+            Dump::Json.render(&moves),
+            "[\n  {\n    \"amount\": 1,\n    \"from\": 2\n  }\n]"
+        );
+    }
+
+    #[test]
+    fn ron_works() {
+        let stacks = (vec![vec!["Z", "N"]], Move { amount: 1, from: 2 });
+
+        assert_eq!(
+            ron::from_str::<ron::Value>(&Dump::Ron.render(&stacks)).unwrap(),
+            ron::from_str::<ron::Value>(r#"([["Z", "N"]], (amount: 1, from: 2))"#).unwrap()
+        );
+    }
+}
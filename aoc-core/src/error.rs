@@ -0,0 +1,117 @@
+use crate::diagnostic::{Diagnostic, Span};
+use crate::input::InputError;
+use crate::recover::Report;
+use nom::error::VerboseError;
+use std::fmt;
+
+/// Everything that can go wrong between reading the input and printing
+/// an answer.
+#[derive(Debug)]
+pub enum AocError {
+    /// The input does not match the puzzle format.
+    Parse(Diagnostic),
+    /// Recovery mode found one or more malformed lines.
+    Malformed(Report),
+    /// The input parsed but makes no sense for the puzzle, e.g. moving
+    /// crates off an empty stack.
+    Validation(String),
+    /// The input could not be found or read.
+    Io(InputError),
+}
+
+impl AocError {
+    /// Process exit code for this error. Each kind gets its own, so
+    /// scripts can tell them apart; 2 is left to clap's usage errors.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::Io(_) => 3,
+            Self::Parse(_) | Self::Malformed(_) => 4,
+            Self::Validation(_) => 5,
+        }
+    }
+
+    /// Short machine readable name for this kind of error.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Parse(_) => "parse",
+            Self::Malformed(_) => "malformed",
+            Self::Validation(_) => "validation",
+            Self::Io(_) => "io",
+        }
+    }
+
+    /// Human readable report, naming `file` as the input it came from.
+    pub fn render(&self, file: &str) -> String {
+        match self {
+            Self::Parse(diagnostic) => diagnostic.render(file),
+            Self::Malformed(report) => report.render(file),
+            _ => format!("error: {}", self),
+        }
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Parse(msg) => write!(f, "parse error: {}", msg),
+            Self::Malformed(report) => write!(f, "parse error: {}", report),
+            Self::Validation(msg) => write!(f, "invalid input: {}", msg),
+            Self::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for AocError {}
+
+impl From<InputError> for AocError {
+    fn from(item: InputError) -> Self {
+        Self::Io(item)
+    }
+}
+
+impl From<nom::Err<VerboseError<Span<'_>>>> for AocError {
+    fn from(item: nom::Err<VerboseError<Span<'_>>>) -> Self {
+        Self::Parse(item.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use nom::character::complete;
+
+    #[test]
+    fn from_nom_works() {
+        let err: AocError = complete::u32::<_, VerboseError<Span>>(Span::new("x1\ny"))
+            .unwrap_err()
+            .into();
+
+        assert_eq!(
+            err.to_string(),
+            "parse error: unexpected input (Digit) at 1:1"
+        );
+        assert_eq!(err.exit_code(), 4);
+    }
+
+    #[test]
+    fn exit_codes_are_distinct() {
+        let codes = [
+            AocError::Parse(Diagnostic {
+                message: String::new(),
+                location: None,
+            })
+            .exit_code(),
+            AocError::Validation(String::new()).exit_code(),
+            AocError::Io(InputError::NotFound {
+                name: String::new(),
+                tried: vec![],
+            })
+            .exit_code(),
+        ];
+
+        assert!(codes.iter().all(|c| *c != 0 && *c != 2));
+        assert_ne!(codes[0], codes[1]);
+        assert_ne!(codes[1], codes[2]);
+        assert_ne!(codes[0], codes[2]);
+    }
+}
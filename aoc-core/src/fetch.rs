@@ -0,0 +1,374 @@
+//! Download puzzle inputs once and keep them in the [`input::cache_dir`].
+
+use crate::input;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Environment variable holding the adventofcode.com session cookie.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Sent with every request, so the site knows who to contact about it.
+pub const USER_AGENT: &str = concat!(
+    "aoc-core/",
+    env!("CARGO_PKG_VERSION"),
+    " (input fetcher by felipe@balbi.sh)"
+);
+
+const SITE: &str = "https://adventofcode.com";
+
+#[derive(Debug)]
+pub enum FetchError {
+    NoSession {
+        config: Option<PathBuf>,
+    },
+    NoCacheDir,
+    Config {
+        path: PathBuf,
+        error: toml::de::Error,
+    },
+    /// The site answered, but not with an input.
+    Status {
+        url: String,
+        status: u16,
+        message: String,
+    },
+    /// The site could not be reached at all.
+    Transport {
+        url: String,
+        message: String,
+    },
+    Io {
+        path: PathBuf,
+        error: io::Error,
+    },
+    /// A cached input that does not match its checksum.
+    Corrupt {
+        path: PathBuf,
+    },
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NoSession { config } => {
+                write!(f, "no session token, set {}", SESSION_VAR)?;
+                if let Some(path) = config {
+                    write!(f, " or `session` in {}", path.display())?;
+                }
+                Ok(())
+            }
+            Self::NoCacheDir => write!(
+                f,
+                "nowhere to cache inputs, set {} or HOME",
+                input::CACHE_DIR_VAR
+            ),
+            Self::Config { path, error } => {
+                write!(f, "could not parse {}: {}", path.display(), error)
+            }
+            Self::Status {
+                url,
+                status,
+                message,
+            } => write!(f, "{} answered {}: {}", url, status, message),
+            Self::Transport { url, message } => {
+                write!(f, "could not reach {}: {}", url, message)
+            }
+            Self::Io { path, error } => write!(f, "{}: {}", path.display(), error),
+            Self::Corrupt { path } => write!(
+                f,
+                "{} does not match its checksum, remove it to download it again",
+                path.display()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Config {
+    session: Option<String>,
+}
+
+/// `aoc/config.toml` in the user's config directory.
+pub fn config_path() -> Option<PathBuf> {
+    let var = |name| std::env::var_os(name).filter(|v| !v.is_empty());
+
+    var("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| var("HOME").map(|dir| Path::new(&dir).join(".config")))
+        .map(|dir| dir.join("aoc").join("config.toml"))
+}
+
+/// The session token from `$AOC_SESSION`, or else from the config file.
+pub fn session() -> Result<String, FetchError> {
+    session_from(std::env::var(SESSION_VAR).ok(), config_path().as_deref())
+}
+
+fn session_from(var: Option<String>, config: Option<&Path>) -> Result<String, FetchError> {
+    let no_session = || FetchError::NoSession {
+        config: config.map(Path::to_owned),
+    };
+
+    if let Some(token) = var.filter(|token| !token.trim().is_empty()) {
+        return Ok(token.trim().to_owned());
+    }
+
+    let path = config.ok_or_else(no_session)?;
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Err(no_session()),
+        Err(error) => {
+            return Err(FetchError::Io {
+                path: path.to_owned(),
+                error,
+            })
+        }
+    };
+    let config: Config = toml::from_str(&text).map_err(|error| FetchError::Config {
+        path: path.to_owned(),
+        error,
+    })?;
+
+    config
+        .session
+        .map(|token| token.trim().to_owned())
+        .filter(|token| !token.is_empty())
+        .ok_or_else(no_session)
+}
+
+/// Hex SHA-256 of `text`.
+fn checksum(text: &str) -> String {
+    Sha256::digest(text)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+fn checksum_path(input: &Path) -> PathBuf {
+    input.with_extension("txt.sha256")
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Fetched {
+    /// Already cached, nothing was downloaded.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+impl Fetched {
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::Cached(path) | Self::Downloaded(path) => path,
+        }
+    }
+}
+
+pub struct Fetcher {
+    /// Where inputs come from; the real site unless testing.
+    pub site: String,
+    pub cache_dir: PathBuf,
+    /// Only needed once something has to be downloaded.
+    pub session: Option<String>,
+}
+
+impl Fetcher {
+    /// A fetcher for the real site, caching in [`input::cache_dir`] and
+    /// taking the [`session`] token if there is one.
+    pub fn from_env() -> Result<Self, FetchError> {
+        let session = match session() {
+            Ok(token) => Some(token),
+            Err(FetchError::NoSession { .. }) => None,
+            Err(e) => return Err(e),
+        };
+
+        Ok(Self {
+            site: SITE.to_owned(),
+            cache_dir: input::cache_dir().ok_or(FetchError::NoCacheDir)?,
+            session,
+        })
+    }
+
+    /// The input for `year`'s `day`, from the cache if it is there and
+    /// downloaded into it otherwise.
+    pub fn fetch(&self, year: u32, day: u32) -> Result<Fetched, FetchError> {
+        let path = input::cached(&self.cache_dir, year, day);
+        let io_error = |path: &Path| {
+            let path = path.to_owned();
+            move |error| FetchError::Io { path, error }
+        };
+
+        match fs::read_to_string(&path) {
+            Ok(text) => {
+                let recorded = fs::read_to_string(checksum_path(&path)).unwrap_or_default();
+
+                if recorded.trim() != checksum(&text) {
+                    return Err(FetchError::Corrupt { path });
+                }
+
+                return Ok(Fetched::Cached(path));
+            }
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+            Err(error) => return Err(io_error(&path)(error)),
+        }
+
+        let text = self.download(year, day)?;
+
+        // The input goes in last, so it is never there without its checksum.
+        let partial = path.with_extension("txt.part");
+        let dir = path.parent().unwrap_or(&self.cache_dir);
+        fs::create_dir_all(dir).map_err(io_error(dir))?;
+        fs::write(&partial, &text).map_err(io_error(&partial))?;
+        fs::write(checksum_path(&path), checksum(&text) + "\n").map_err(io_error(&path))?;
+        fs::rename(&partial, &path).map_err(io_error(&path))?;
+
+        Ok(Fetched::Downloaded(path))
+    }
+
+    fn download(&self, year: u32, day: u32) -> Result<String, FetchError> {
+        let url = format!("{}/{}/day/{}/input", self.site, year, day);
+        let session = self.session.as_ref().ok_or_else(|| FetchError::NoSession {
+            config: config_path(),
+        })?;
+
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        let response = agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", session))
+            .call()
+            .map_err(|e| match e {
+                ureq::Error::Status(status, response) => FetchError::Status {
+                    url: url.clone(),
+                    status,
+                    message: response
+                        .into_string()
+                        .ok()
+                        .and_then(|body| body.lines().next().map(str::to_owned))
+                        .unwrap_or_default(),
+                },
+                ureq::Error::Transport(e) => FetchError::Transport {
+                    url: url.clone(),
+                    message: match e.message() {
+                        Some(message) => format!("{}: {}", e.kind(), message),
+                        None => e.kind().to_string(),
+                    },
+                },
+            })?;
+
+        response.into_string().map_err(|e| FetchError::Transport {
+            url,
+            message: e.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+
+    /// Serve `body` to every request on a local port, passing the request
+    /// head, lowercased, back through the receiver.
+    fn serve(status: &'static str, body: &'static str) -> (String, mpsc::Receiver<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let site = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let request = BufReader::new(&stream)
+                    .lines()
+                    .map(|line| line.unwrap().to_lowercase())
+                    .take_while(|line| !line.is_empty())
+                    .collect();
+                write!(
+                    stream,
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+                sender.send(request).unwrap();
+            }
+        });
+
+        (site, receiver)
+    }
+
+    fn cache_dir(test: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}-{}", test, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn downloads_once() {
+        let (site, requests) = serve("200 OK", "1000\n2000\n");
+        let fetcher = Fetcher {
+            site,
+            cache_dir: cache_dir("once"),
+            session: Some("53cr37".to_owned()),
+        };
+
+        let path = input::cached(&fetcher.cache_dir, 2022, 1);
+        assert_eq!(
+            fetcher.fetch(2022, 1).unwrap(),
+            Fetched::Downloaded(path.clone())
+        );
+        assert_eq!(
+            fetcher.fetch(2022, 1).unwrap(),
+            Fetched::Cached(path.clone())
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1000\n2000\n");
+
+        let request = requests.recv().unwrap();
+        assert_eq!(request[0], "get /2022/day/1/input http/1.1");
+        assert!(request.contains(&"cookie: session=53cr37".to_owned()));
+        assert!(request.contains(&format!("user-agent: {}", USER_AGENT.to_lowercase())));
+        assert!(requests.try_recv().is_err());
+
+        fs::write(&path, "1000\n").unwrap();
+        assert!(matches!(
+            fetcher.fetch(2022, 1),
+            Err(FetchError::Corrupt { .. })
+        ));
+
+        fs::remove_dir_all(&fetcher.cache_dir).unwrap();
+    }
+
+    #[test]
+    fn refusals_are_not_cached() {
+        let (site, _requests) = serve("400 Bad Request", "Please log in.\n");
+        let fetcher = Fetcher {
+            site,
+            cache_dir: cache_dir("refused"),
+            session: Some("expired".to_owned()),
+        };
+
+        match fetcher.fetch(2022, 2) {
+            Err(FetchError::Status {
+                status, message, ..
+            }) => assert_eq!((status, message.as_str()), (400, "Please log in.")),
+            other => panic!("expected a status error, got {:?}", other),
+        }
+        assert!(!input::cached(&fetcher.cache_dir, 2022, 2).exists());
+        assert!(matches!(
+            session_from(None, None),
+            Err(FetchError::NoSession { config: None })
+        ));
+    }
+}
@@ -0,0 +1,81 @@
+//! Randomness for the days' input generators.
+
+use std::ops::RangeInclusive;
+
+/// A small seeded pseudo random number generator (SplitMix64).
+///
+/// Unlike `rand`'s `StdRng` its sequence for a given seed never
+/// changes, so a seed is enough to reproduce a generated input.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `range`, which must not be empty.
+    pub fn range(&mut self, range: RangeInclusive<usize>) -> usize {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "empty range {}..={}", start, end);
+
+        let span = (end - start) as u64 + 1;
+        start + (self.next_u64() % span) as usize
+    }
+
+    /// True one time in `n`.
+    pub fn one_in(&mut self, n: usize) -> bool {
+        self.range(1..=n) == 1
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..=items.len() - 1)]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range(0..=i));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn seeds_reproduce() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+
+        let a = (0..100).map(|_| a.next_u64()).collect::<Vec<_>>();
+        let b = (0..100).map(|_| b.next_u64()).collect::<Vec<_>>();
+
+        assert_eq!(a, b);
+        assert_ne!(Rng::new(42).next_u64(), Rng::new(43).next_u64());
+    }
+
+    #[test]
+    fn range_stays_in_bounds() {
+        let mut rng = Rng::new(0);
+
+        for _ in 0..1000 {
+            assert!((3..=5).contains(&rng.range(3..=5)));
+        }
+        assert_eq!(rng.range(7..=7), 7);
+
+        let mut items = (0..10).collect::<Vec<_>>();
+        rng.shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, (0..10).collect::<Vec<_>>());
+    }
+}
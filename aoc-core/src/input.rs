@@ -0,0 +1,251 @@
+use crate::Runner;
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+/// Environment variable naming a directory laid out like the workspace,
+/// i.e. holding `<name>/input.txt` for every day.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Environment variable overriding where downloaded inputs are cached.
+pub const CACHE_DIR_VAR: &str = "AOC_CACHE_DIR";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Stdin => write!(f, "<stdin>"),
+            Self::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound {
+        name: String,
+        tried: Vec<PathBuf>,
+    },
+    Io {
+        source: Source,
+        error: io::Error,
+    },
+    /// An `answers.toml` that is not valid.
+    Answers {
+        path: PathBuf,
+        error: toml::de::Error,
+    },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NotFound { name, tried } => {
+                write!(f, "no input found for {}, tried:", name)?;
+                for path in tried {
+                    write!(f, "\n  {}", path.display())?;
+                }
+                write!(
+                    f,
+                    "\nuse --input <PATH>, set {} or download it with `aoc fetch`",
+                    INPUT_DIR_VAR
+                )
+            }
+            Self::Io { source, error } => write!(f, "could not read {}: {}", source, error),
+            Self::Answers { path, error } => {
+                write!(f, "could not parse {}: {}", path.display(), error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Where downloaded inputs live: `$AOC_CACHE_DIR`, or an `aoc`
+/// directory in the user's cache directory.
+pub fn cache_dir() -> Option<PathBuf> {
+    let var = |name| std::env::var_os(name).filter(|v| !v.is_empty());
+
+    var(CACHE_DIR_VAR)
+        .map(PathBuf::from)
+        .or_else(|| var("XDG_CACHE_HOME").map(|dir| Path::new(&dir).join("aoc")))
+        .or_else(|| var("HOME").map(|dir| Path::new(&dir).join(".cache").join("aoc")))
+}
+
+/// The cached input for `year`'s `day` in `cache_dir`.
+pub fn cached(cache_dir: &Path, year: u32, day: u32) -> PathBuf {
+    cache_dir
+        .join(year.to_string())
+        .join(day.to_string())
+        .join("input.txt")
+}
+
+/// Every location an input for `solution` is looked up in, most
+/// specific first.
+fn candidates(
+    solution: &dyn Runner,
+    input_dir: Option<&Path>,
+    cache_dir: Option<&Path>,
+) -> Vec<PathBuf> {
+    let mut paths = vec![];
+
+    if let Some(dir) = input_dir {
+        paths.push(dir.join(solution.name()).join("input.txt"));
+    }
+
+    paths.push(Path::new(solution.manifest_dir()).join("input.txt"));
+
+    if let Some(dir) = cache_dir {
+        paths.push(cached(dir, solution.year(), solution.day()));
+    }
+
+    paths
+}
+
+/// Pick where the input for `solution` comes from.
+///
+/// An explicit path always wins, `-` meaning stdin. Otherwise
+/// `$AOC_INPUT_DIR/<name>/input.txt` is tried before the day crate's
+/// own `input.txt`, and a downloaded one in the [`cache_dir`] after it.
+pub fn resolve(solution: &dyn Runner, explicit: Option<&Path>) -> Result<Source, InputError> {
+    let input_dir = std::env::var_os(INPUT_DIR_VAR).map(PathBuf::from);
+
+    resolve_with(
+        solution,
+        explicit,
+        input_dir.as_deref(),
+        cache_dir().as_deref(),
+    )
+}
+
+fn resolve_with(
+    solution: &dyn Runner,
+    explicit: Option<&Path>,
+    input_dir: Option<&Path>,
+    cache_dir: Option<&Path>,
+) -> Result<Source, InputError> {
+    match explicit {
+        Some(path) if path == Path::new("-") => return Ok(Source::Stdin),
+        Some(path) => return Ok(Source::File(path.to_owned())),
+        None => {}
+    }
+
+    let tried = candidates(solution, input_dir, cache_dir);
+
+    match tried.iter().find(|path| path.is_file()) {
+        Some(path) => Ok(Source::File(path.to_owned())),
+        None => Err(InputError::NotFound {
+            name: solution.name().to_owned(),
+            tried,
+        }),
+    }
+}
+
+/// Open `source` for reading a piece at a time, see [`crate::stream`].
+pub fn open(source: &Source) -> Result<Box<dyn BufRead>, InputError> {
+    match source {
+        Source::Stdin => Ok(Box::new(io::stdin().lock())),
+        Source::File(path) => fs::File::open(path)
+            .map(|file| Box::new(BufReader::new(file)) as Box<dyn BufRead>)
+            .map_err(|error| InputError::Io {
+                source: source.clone(),
+                error,
+            }),
+    }
+}
+
+pub fn read(source: &Source) -> Result<String, InputError> {
+    let result = match source {
+        Source::Stdin => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input).map(|_| input)
+        }
+        Source::File(path) => fs::read_to_string(path),
+    };
+
+    result.map_err(|error| InputError::Io {
+        source: source.clone(),
+        error,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Answer, AocError, ParseResult, Solution, Span};
+    use nom::combinator::{map, rest};
+
+    struct Missing;
+
+    impl Solution for Missing {
+        type Input<'a> = &'a str;
+
+        fn name(&self) -> &'static str {
+            "missing"
+        }
+
+        fn year(&self) -> u32 {
+            2022
+        }
+
+        fn day(&self) -> u32 {
+            0
+        }
+
+        fn manifest_dir(&self) -> &'static str {
+            "/nonexistent/missing"
+        }
+
+        fn parse_span<'a>(&self, input: Span<'a>) -> ParseResult<'a, Self::Input<'a>> {
+            map(rest, |span: Span<'a>| *span.fragment())(input)
+        }
+
+        fn part1(&self, input: &Self::Input<'_>) -> Result<Answer, AocError> {
+            Ok(input.len().into())
+        }
+
+        fn part2(&self, input: &Self::Input<'_>) -> Result<Answer, AocError> {
+            Ok(input.len().into())
+        }
+    }
+
+    #[test]
+    fn explicit_wins() {
+        let dir = Path::new("/nonexistent/dir");
+
+        assert_eq!(
+            resolve_with(&Missing, Some(Path::new("-")), Some(dir), None).unwrap(),
+            Source::Stdin
+        );
+        assert_eq!(
+            resolve_with(&Missing, Some(Path::new("a.txt")), Some(dir), None).unwrap(),
+            Source::File(PathBuf::from("a.txt"))
+        );
+    }
+
+    #[test]
+    fn not_found_lists_candidates() {
+        let err = resolve_with(
+            &Missing,
+            None,
+            Some(Path::new("/nonexistent/dir")),
+            Some(Path::new("/nonexistent/cache")),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "no input found for missing, tried:
+  /nonexistent/dir/missing/input.txt
+  /nonexistent/missing/input.txt
+  /nonexistent/cache/2022/0/input.txt
+use --input <PATH>, set AOC_INPUT_DIR or download it with `aoc fetch`"
+        );
+    }
+}
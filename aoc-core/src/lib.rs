@@ -0,0 +1,31 @@
+mod answer;
+#[cfg(feature = "bench")]
+pub mod bench;
+pub mod bytes;
+mod cli;
+mod diagnostic;
+mod dump;
+mod error;
+#[cfg(feature = "fetch")]
+pub mod fetch;
+pub mod gen;
+pub mod input;
+pub mod lint;
+mod normalize;
+pub mod oracle;
+pub mod output;
+mod parse;
+pub mod recover;
+pub mod shrink;
+mod solution;
+pub mod stream;
+pub mod verify;
+
+pub use answer::Answer;
+pub use cli::{main, Cli};
+pub use diagnostic::{Diagnostic, Location, ParseResult, Span};
+pub use dump::Dump;
+pub use error::AocError;
+pub use normalize::normalize;
+pub use parse::finish;
+pub use solution::{Options, Part, Run, Runner, Solution, Solved};
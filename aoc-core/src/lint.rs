@@ -0,0 +1,88 @@
+//! Checks for inputs that parse fine but make no sense to the solvers.
+
+use crate::diagnostic::{self, Location, ParseResult, Span};
+use clap::ValueEnum;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+pub enum Severity {
+    /// Odd, but harmless.
+    Info,
+    /// Likely to make an answer wrong.
+    Warning,
+    /// Makes a solver fail.
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Info => write!(f, "info"),
+            Self::Warning => write!(f, "warning"),
+            Self::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// One suspicious spot in an input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lint {
+    pub severity: Severity,
+    /// Short, kebab-case name of the rule that fired.
+    pub rule: &'static str,
+    pub message: String,
+    pub location: Option<Location>,
+}
+
+impl Lint {
+    /// A lint pointing at the start of `span`.
+    pub fn at(severity: Severity, rule: &'static str, message: String, span: &Span) -> Self {
+        Self {
+            severity,
+            rule,
+            message,
+            location: Some(Location::of(span)),
+        }
+    }
+
+    pub fn render(&self, file: &str) -> String {
+        let label = format!("{}[{}]", self.severity, self.rule);
+
+        diagnostic::render(&label, &self.message, self.location.as_ref(), file)
+    }
+}
+
+/// Run `parser`, keeping the span it started at so a [`Lint`] can point
+/// there. Unlike [`nom::combinator::consumed`], the span runs on to the
+/// end of the line, so the rendered snippet shows all of it.
+pub fn marked<'a, O>(
+    mut parser: impl FnMut(Span<'a>) -> ParseResult<'a, O>,
+) -> impl FnMut(Span<'a>) -> ParseResult<'a, (Span<'a>, O)> {
+    move |input| parser(input).map(|(rest, output)| (rest, (input, output)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use nom::Slice;
+
+    #[test]
+    fn render_names_the_rule() {
+        let input = Span::new("2-4,6-8\n8-2,3-4\n");
+        let lint = Lint::at(
+            Severity::Warning,
+            "reversed-range",
+            "`8-2` is empty".to_owned(),
+            &input.slice(8..),
+        );
+
+        assert_eq!(
+            lint.render("input.txt"),
+            "warning[reversed-range]: `8-2` is empty
+ --> input.txt:2:1
+  |
+2 | 8-2,3-4
+  | ^"
+        );
+    }
+}
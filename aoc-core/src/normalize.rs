@@ -0,0 +1,53 @@
+use std::borrow::Cow;
+
+const BOM: char = '\u{feff}';
+
+/// Bring `input` into the one shape every day's parser expects.
+///
+/// A leading UTF-8 byte order mark is dropped, `\r\n` becomes `\n` and
+/// any run of trailing line endings becomes exactly one `\n`. Trailing
+/// spaces inside lines are kept: day 5's crate drawing depends on them.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let input = input.strip_prefix(BOM).unwrap_or(input);
+    let body = input.trim_end_matches(['\r', '\n']);
+
+    if body.is_empty() {
+        return Cow::Borrowed(body);
+    }
+
+    if !body.contains('\r') && input.len() == body.len() + 1 && input.ends_with('\n') {
+        return Cow::Borrowed(input);
+    }
+
+    let mut normalized = body.replace("\r\n", "\n");
+    normalized.push('\n');
+
+    Cow::Owned(normalized)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn normalize_works() {
+        assert_eq!(
+            normalize("\u{feff}1\r\n2\r\n\r\n3\r\n\r\n\r\n"),
+            "1\n2\n\n3\n"
+        );
+        assert_eq!(normalize("1\n2"), "1\n2\n");
+        assert_eq!(
+            normalize("    [D]\n[N] [C]    \n"),
+            "    [D]\n[N] [C]    \n"
+        );
+        assert_eq!(normalize("\n\n"), "");
+        assert_eq!(normalize("1\r"), "1\n");
+    }
+
+    #[test]
+    fn normalized_input_is_borrowed() {
+        assert!(matches!(normalize("1\n2\n"), Cow::Borrowed(_)));
+        assert!(matches!(normalize("\u{feff}1\n"), Cow::Borrowed(_)));
+        assert!(matches!(normalize("1\r\n"), Cow::Owned(_)));
+    }
+}
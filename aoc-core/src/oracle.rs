@@ -0,0 +1,67 @@
+//! Differential testing of a day's solvers against deliberately naive
+//! reference implementations, over inputs from [`Solution::generate`].
+
+use crate::gen::Rng;
+use crate::{Answer, Part, Solution};
+
+/// Seeds tried for every size.
+const SEEDS: u64 = 20;
+
+/// The largest size handed to [`Solution::generate`].
+const MAX_SIZE: usize = 30;
+
+/// Compare `part` of `solution` with `oracle` on generated inputs, and
+/// panic with the smallest input they disagree on. An oracle returns
+/// `None` where it expects the solver to fail.
+pub fn differential<S, O>(solution: &S, part: Part, oracle: O)
+where
+    S: Solution,
+    O: for<'a> Fn(&S::Input<'a>) -> Option<Answer>,
+{
+    let mut smallest: Option<(String, String)> = None;
+
+    for seed in 0..SEEDS {
+        for size in 1..=MAX_SIZE {
+            let input = solution
+                .generate(&mut Rng::new(seed), size)
+                .unwrap_or_else(|| panic!("{} has no input generator", solution.name()));
+
+            if smallest
+                .as_ref()
+                .is_some_and(|(s, _)| s.len() <= input.len())
+            {
+                continue;
+            }
+
+            let parsed = solution
+                .parse(&input)
+                .unwrap_or_else(|e| panic!("generated input does not parse, {}:\n{}", e, input));
+
+            let expected = oracle(&parsed);
+            let actual = match part {
+                Part::One => solution.part1(&parsed),
+                Part::Two => solution.part2(&parsed),
+            };
+
+            if actual.as_ref().ok() == expected.as_ref() {
+                continue;
+            }
+
+            let expected = expected.map_or_else(|| "no answer".to_owned(), |a| a.to_string());
+            let actual = actual.map_or_else(|e| e.to_string(), |a| a.to_string());
+            drop(parsed);
+
+            smallest = Some((input, format!("expected {}, got {}", expected, actual)));
+        }
+    }
+
+    if let Some((input, verdict)) = smallest {
+        panic!(
+            "part {} of {} disagrees with its oracle, {}, on:\n{}",
+            part,
+            solution.name(),
+            verdict,
+            input
+        );
+    }
+}
@@ -0,0 +1,249 @@
+use crate::{Answer, AocError, Diagnostic, Part, Run, Runner};
+use clap::ValueEnum;
+use serde::Serialize;
+
+/// How answers are printed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// `Part N: <answer>` lines for people.
+    #[default]
+    Text,
+    /// One JSON object per day, one per line.
+    Json,
+    /// One row per part under [`CSV_HEADER`].
+    Csv,
+}
+
+pub const CSV_HEADER: &str = "year,day,name,part,answer,parse_ns,solve_ns,error";
+
+/// The outcome of running one day, in the shape `--format json` and
+/// `--format csv` emit. Field names are part of the output schema.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Record {
+    pub year: u32,
+    pub day: u32,
+    pub name: String,
+    pub parse_ns: Option<u64>,
+    pub parts: Vec<PartRecord>,
+    pub error: Option<ErrorRecord>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PartRecord {
+    pub part: u8,
+    pub answer: Answer,
+    pub solve_ns: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ErrorRecord {
+    pub kind: &'static str,
+    pub exit_code: i32,
+    pub message: String,
+    pub diagnostics: Vec<DiagnosticRecord>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DiagnosticRecord {
+    pub message: String,
+    pub line: Option<u32>,
+    pub column: Option<usize>,
+}
+
+impl From<&Diagnostic> for DiagnosticRecord {
+    fn from(item: &Diagnostic) -> Self {
+        Self {
+            message: item.message.clone(),
+            line: item.location.as_ref().map(|l| l.line),
+            column: item.location.as_ref().map(|l| l.column),
+        }
+    }
+}
+
+impl From<&AocError> for ErrorRecord {
+    fn from(item: &AocError) -> Self {
+        let diagnostics = match item {
+            AocError::Parse(diagnostic) => vec![diagnostic.into()],
+            AocError::Malformed(report) => report.errors.iter().map(Into::into).collect(),
+            _ => vec![],
+        };
+
+        Self {
+            kind: item.kind(),
+            exit_code: item.exit_code(),
+            message: item.to_string(),
+            diagnostics,
+        }
+    }
+}
+
+impl Record {
+    pub fn new(solution: &dyn Runner, result: &Result<Run, AocError>) -> Self {
+        let (parse_ns, parts, error) = match result {
+            Ok(run) => {
+                let parts = run
+                    .parts
+                    .iter()
+                    .map(|solved| PartRecord {
+                        part: match solved.part {
+                            Part::One => 1,
+                            Part::Two => 2,
+                        },
+                        answer: solved.answer.clone(),
+                        solve_ns: solved.elapsed.as_nanos() as u64,
+                    })
+                    .collect();
+
+                (Some(run.parse.as_nanos() as u64), parts, None)
+            }
+            Err(e) => (None, vec![], Some(e.into())),
+        };
+
+        Self {
+            year: solution.year(),
+            day: solution.day(),
+            name: solution.name().to_owned(),
+            parse_ns,
+            parts,
+            error,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("records always serialize")
+    }
+
+    /// Rows to go under [`CSV_HEADER`]: one per part, or a single row
+    /// without a part when running the day failed.
+    pub fn to_csv(&self) -> Vec<String> {
+        let prefix = format!("{},{},{}", self.year, self.day, csv_field(&self.name));
+        let parse_ns = self.parse_ns.map(|ns| ns.to_string()).unwrap_or_default();
+
+        if let Some(error) = &self.error {
+            return vec![format!("{},,,,,{}", prefix, csv_field(&error.message))];
+        }
+
+        self.parts
+            .iter()
+            .map(|part| {
+                format!(
+                    "{},{},{},{},{},",
+                    prefix,
+                    part.part,
+                    csv_field(&part.answer.to_string()),
+                    parse_ns,
+                    part.solve_ns
+                )
+            })
+            .collect()
+    }
+}
+
+/// Quote `field` if it would otherwise break the row apart.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Location, ParseResult, Solution, Solved, Span};
+    use nom::combinator::{map, rest};
+    use std::time::Duration;
+
+    struct Day;
+
+    impl Solution for Day {
+        type Input<'a> = &'a str;
+
+        fn name(&self) -> &'static str {
+            "aoc2022d5"
+        }
+
+        fn year(&self) -> u32 {
+            2022
+        }
+
+        fn day(&self) -> u32 {
+            5
+        }
+
+        fn manifest_dir(&self) -> &'static str {
+            "/nonexistent"
+        }
+
+        fn parse_span<'a>(&self, input: Span<'a>) -> ParseResult<'a, Self::Input<'a>> {
+            map(rest, |span: Span<'a>| *span.fragment())(input)
+        }
+
+        fn part1(&self, input: &Self::Input<'_>) -> Result<Answer, AocError> {
+            Ok(input.len().into())
+        }
+
+        fn part2(&self, input: &Self::Input<'_>) -> Result<Answer, AocError> {
+            Ok(input.len().into())
+        }
+    }
+
+    fn run() -> Run {
+        Run {
+            parse: Duration::from_nanos(1500),
+            parts: vec![
+                Solved {
+                    part: Part::One,
+                    answer: 24000u32.into(),
+                    elapsed: Duration::from_nanos(20),
+                },
+                Solved {
+                    part: Part::Two,
+                    answer: "MCD".into(),
+                    elapsed: Duration::from_nanos(30),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn json_schema_is_stable() {
+        assert_eq!(
+            Record::new(&Day, &Ok(run())).to_json(),
+            r#"{"year":2022,"day":5,"name":"aoc2022d5","parse_ns":1500,"parts":[{"part":1,"answer":24000,"solve_ns":20},{"part":2,"answer":"MCD","solve_ns":30}],"error":null}"#
+        );
+
+        let err = AocError::Parse(Diagnostic {
+            message: "expected `move N from A to B`".to_owned(),
+            location: Some(Location {
+                line: 4,
+                column: 6,
+                source_line: "move x from 1 to 2".to_owned(),
+            }),
+        });
+
+        assert_eq!(
+            Record::new(&Day, &Err(err)).to_json(),
+            r#"{"year":2022,"day":5,"name":"aoc2022d5","parse_ns":null,"parts":[],"error":{"kind":"parse","exit_code":4,"message":"parse error: expected `move N from A to B` at 4:6","diagnostics":[{"message":"expected `move N from A to B`","line":4,"column":6}]}}"#
+        );
+    }
+
+    #[test]
+    fn csv_schema_is_stable() {
+        assert_eq!(
+            Record::new(&Day, &Ok(run())).to_csv(),
+            vec![
+                "2022,5,aoc2022d5,1,24000,1500,20,",
+                "2022,5,aoc2022d5,2,MCD,1500,30,",
+            ]
+        );
+
+        let err = AocError::Validation("stack 1, or 2, ends up empty".to_owned());
+
+        assert_eq!(
+            Record::new(&Day, &Err(err)).to_csv(),
+            vec![r#"2022,5,aoc2022d5,,,,,"invalid input: stack 1, or 2, ends up empty""#]
+        );
+    }
+}
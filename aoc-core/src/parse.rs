@@ -0,0 +1,64 @@
+use crate::diagnostic::{Diagnostic, Location, ParseResult, Span};
+use crate::AocError;
+use nom::{character::complete::multispace0, error::VerboseError};
+
+/// Run a day's top-level `parser` over all of `input`.
+///
+/// Parsers built from `separated_list1` or `many1` quietly stop at the
+/// first line they do not understand, so anything left over other than
+/// trailing whitespace is an error pointing at where parsing stopped.
+/// `lenient` restores the old behaviour of ignoring it.
+pub fn finish<'a, O, P>(input: &'a str, lenient: bool, parser: P) -> Result<O, AocError>
+where
+    P: FnOnce(Span<'a>) -> ParseResult<'a, O>,
+{
+    let (rest, output) = parser(Span::new(input))?;
+
+    if lenient {
+        return Ok(output);
+    }
+
+    let (rest, _) = multispace0::<_, VerboseError<Span>>(rest)?;
+
+    if rest.fragment().is_empty() {
+        return Ok(output);
+    }
+
+    Err(AocError::Parse(Diagnostic {
+        message: "unexpected input, parsing stopped here".to_owned(),
+        location: Some(Location::of(&rest)),
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use nom::{
+        character::complete::{self, newline},
+        multi::separated_list1,
+    };
+
+    fn numbers(input: Span<'_>) -> ParseResult<'_, Vec<u32>> {
+        separated_list1(newline, complete::u32)(input)
+    }
+
+    #[test]
+    fn trailing_whitespace_is_fine() {
+        assert_eq!(finish("1\n2\n\n \n", false, numbers).unwrap(), vec![1, 2]);
+    }
+
+    #[test]
+    fn leftover_is_located() {
+        let err = finish("1\n2\nthree\n4\n", false, numbers).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "parse error: unexpected input, parsing stopped here at 3:1"
+        );
+        assert!(err.render("input.txt").contains("3 | three\n"));
+        assert_eq!(
+            finish("1\n2\nthree\n4\n", true, numbers).unwrap(),
+            vec![1, 2]
+        );
+    }
+}
@@ -0,0 +1,142 @@
+use crate::diagnostic::{Diagnostic, Span};
+use crate::AocError;
+use nom::{
+    character::complete::{line_ending, not_line_ending},
+    combinator::{all_consuming, opt},
+    error::VerboseError,
+    sequence::pair,
+    IResult,
+};
+use std::fmt;
+
+/// Every malformed line found while parsing in recovery mode.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub errors: Vec<Diagnostic>,
+    pub parsed: usize,
+    pub lines: usize,
+}
+
+impl Report {
+    /// Hand back `value` if nothing went wrong, otherwise the whole report.
+    pub fn finish<T>(mut self, value: T) -> Result<T, AocError> {
+        if self.errors.is_empty() {
+            return Ok(value);
+        }
+
+        self.errors
+            .sort_by_key(|e| e.location.as_ref().map(|l| (l.line, l.column)));
+
+        Err(AocError::Malformed(self))
+    }
+
+    pub fn render(&self, file: &str) -> String {
+        let mut out = self
+            .errors
+            .iter()
+            .map(|e| e.render(file))
+            .collect::<Vec<_>>();
+
+        out.push(format!("error: {}", self));
+
+        out.join("\n\n")
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} malformed line{}, {} of {} lines parsed",
+            self.errors.len(),
+            if self.errors.len() == 1 { "" } else { "s" },
+            self.parsed,
+            self.lines
+        )
+    }
+}
+
+type LineResult<'a> = IResult<Span<'a>, (Span<'a>, Option<Span<'a>>), VerboseError<Span<'a>>>;
+
+fn next_line(input: Span<'_>) -> LineResult<'_> {
+    pair(not_line_ending, opt(line_ending))(input)
+}
+
+/// Run `parser` over each line of `input` on its own. A line `parser`
+/// does not consume entirely is recorded in the [`Report`] and parsing
+/// carries on with the next one.
+///
+/// Every item comes back with the line it was parsed from, for days
+/// that need to point at a line when putting items back together.
+pub fn lines<'a, O, P>(input: Span<'a>, mut parser: P) -> (Vec<(Span<'a>, O)>, Report)
+where
+    P: FnMut(Span<'a>) -> IResult<Span<'a>, O, VerboseError<Span<'a>>>,
+{
+    let mut items = vec![];
+    let mut report = Report {
+        errors: vec![],
+        parsed: 0,
+        lines: 0,
+    };
+    let mut input = input;
+
+    while !input.fragment().is_empty() {
+        let (rest, (line, _)) = match next_line(input) {
+            Ok(next) => next,
+            Err(_) => break,
+        };
+
+        match all_consuming(&mut parser)(line) {
+            Ok((_, item)) => {
+                items.push((line, item));
+                report.parsed += 1;
+            }
+            Err(e) => report.errors.push(e.into()),
+        }
+
+        report.lines += 1;
+        input = rest;
+    }
+
+    (items, report)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use nom::{character::complete, error::context};
+
+    fn number(input: Span<'_>) -> IResult<Span<'_>, u32, VerboseError<Span<'_>>> {
+        context("a number", complete::u32)(input)
+    }
+
+    #[test]
+    fn lines_collects_every_error() {
+        let (items, report) = lines(Span::new("1\nx\n3\n4y\n5\n"), number);
+
+        assert_eq!(
+            items.iter().map(|(_, n)| *n).collect::<Vec<_>>(),
+            vec![1, 3, 5]
+        );
+        assert_eq!(report.to_string(), "2 malformed lines, 3 of 5 lines parsed");
+        assert_eq!(
+            report
+                .errors
+                .iter()
+                .map(|e| e.to_string())
+                .collect::<Vec<_>>(),
+            vec![
+                "expected a number at 2:1",
+                "unexpected trailing input at 4:2"
+            ]
+        );
+    }
+
+    #[test]
+    fn finish_works() {
+        let (items, report) = lines(Span::new("1\r\n2"), number);
+
+        assert_eq!(report.lines, 2);
+        assert_eq!(report.finish(items.len()).unwrap(), 2);
+    }
+}
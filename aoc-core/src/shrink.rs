@@ -0,0 +1,258 @@
+//! Delta debugging of puzzle inputs, down to the smallest input that
+//! still shows a failure.
+
+use crate::{normalize, Answer, AocError, Options, Part, Runner};
+use std::panic::{self, AssertUnwindSafe};
+
+/// Puts the remaining pieces of a [`Split`] back together.
+pub type Join = Box<dyn Fn(&[String]) -> String>;
+
+/// One way of taking an input apart into pieces that can be dropped
+/// independently, and of putting whatever is left back together.
+pub struct Split {
+    pub pieces: Vec<String>,
+    pub join: Join,
+}
+
+impl Split {
+    /// Pieces separated by `separator`, such as `"\n"` for lines or
+    /// `"\n\n"` for blocks.
+    pub fn separated(input: &str, separator: &'static str) -> Self {
+        let pieces = input
+            .trim_end_matches('\n')
+            .split(separator)
+            .map(str::to_owned)
+            .collect();
+
+        Self {
+            pieces,
+            join: Box::new(move |pieces| pieces.join(separator) + "\n"),
+        }
+    }
+
+    pub fn lines(input: &str) -> Self {
+        Self::separated(input, "\n")
+    }
+
+    /// Runs of lines, each starting at a line `starts` accepts.
+    pub fn blocks(input: &str, starts: impl Fn(&str) -> bool) -> Self {
+        let mut pieces: Vec<String> = vec![];
+
+        for line in input.lines() {
+            match pieces.last_mut() {
+                Some(piece) if !starts(line) => {
+                    piece.push('\n');
+                    piece.push_str(line);
+                }
+                _ => pieces.push(line.to_owned()),
+            }
+        }
+
+        Self {
+            pieces,
+            join: Box::new(|pieces| pieces.join("\n") + "\n"),
+        }
+    }
+
+    /// Groups of `n` lines.
+    pub fn chunks(input: &str, n: usize) -> Self {
+        let lines = input.lines().collect::<Vec<_>>();
+
+        Self {
+            pieces: lines.chunks(n).map(|chunk| chunk.join("\n")).collect(),
+            join: Box::new(|pieces| pieces.join("\n") + "\n"),
+        }
+    }
+
+    /// Keep `head` in front of whatever the pieces join into.
+    pub fn after(self, head: &str) -> Self {
+        let head = head.to_owned();
+        let join = self.join;
+
+        Self {
+            pieces: self.pieces,
+            join: Box::new(move |pieces| head.clone() + &join(pieces)),
+        }
+    }
+}
+
+/// What makes an input worth keeping while shrinking.
+#[derive(Debug, Clone)]
+pub enum Predicate {
+    /// Parsing or solving panics.
+    Panics,
+    /// The part solves, but not to this answer.
+    Differs(Answer),
+    /// Parsing fails with the same message as for the original input.
+    ParseError,
+}
+
+fn parse_message(e: &AocError) -> Option<&str> {
+    match e {
+        AocError::Parse(diagnostic) => Some(&diagnostic.message),
+        AocError::Malformed(report) => report.errors.first().map(|e| e.message.as_str()),
+        _ => None,
+    }
+}
+
+/// Drop pieces of `split`, halving how many go at once whenever none
+/// can, for as long as `keeps` holds. `None` if nothing could go.
+fn reduce(split: &Split, keeps: &mut impl FnMut(&str) -> bool) -> Option<String> {
+    let mut pieces = split.pieces.clone();
+    let mut size = (pieces.len() / 2).max(1);
+    let mut changed = false;
+
+    loop {
+        let mut start = 0;
+        let mut removed = false;
+
+        while start < pieces.len() {
+            let end = (start + size).min(pieces.len());
+            let candidate = [&pieces[..start], &pieces[end..]].concat();
+
+            if keeps(&(split.join)(&candidate)) {
+                pieces = candidate;
+                removed = true;
+            } else {
+                start = end;
+            }
+        }
+
+        changed |= removed;
+
+        if !removed {
+            if size == 1 {
+                break;
+            }
+            size /= 2;
+        }
+    }
+
+    changed.then(|| (split.join)(&pieces))
+}
+
+/// Shrink `input` for as long as `predicate` holds for `part` of `day`,
+/// trying the day's coarsest [`Split`] first. `None` if the predicate
+/// does not hold for `input` to begin with.
+pub fn shrink(day: &dyn Runner, part: Part, input: &str, predicate: &Predicate) -> Option<String> {
+    let input = normalize(input).into_owned();
+
+    let run = |text: &str| {
+        panic::catch_unwind(AssertUnwindSafe(|| {
+            day.run(text, &[part], &Options::default())
+        }))
+    };
+
+    // Expected panics would drown the output otherwise.
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let original = match (predicate, run(&input)) {
+        (Predicate::ParseError, Ok(Err(e))) => parse_message(&e).map(str::to_owned),
+        _ => None,
+    };
+
+    let mut keeps = |text: &str| match (predicate, run(text)) {
+        (Predicate::Panics, result) => result.is_err(),
+        (Predicate::Differs(expected), Ok(Ok(run))) => run.answer(part) != Some(expected),
+        (Predicate::ParseError, Ok(Err(e))) => {
+            original.is_some() && parse_message(&e) == original.as_deref()
+        }
+        _ => false,
+    };
+
+    let mut current = keeps(&input).then_some(input);
+
+    while let Some(text) = &current {
+        match day
+            .splits(text)
+            .iter()
+            .find_map(|split| reduce(split, &mut keeps))
+        {
+            Some(smaller) => current = Some(smaller),
+            None => break,
+        }
+    }
+
+    panic::set_hook(hook);
+    current
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{ParseResult, Solution, Span};
+    use nom::combinator::{map, rest};
+    use std::cell::Cell;
+
+    /// Panics on any input with an `x` in it.
+    struct Fragile;
+
+    impl Solution for Fragile {
+        type Input<'a> = &'a str;
+
+        fn name(&self) -> &'static str {
+            "fragile"
+        }
+
+        fn year(&self) -> u32 {
+            2022
+        }
+
+        fn day(&self) -> u32 {
+            0
+        }
+
+        fn manifest_dir(&self) -> &'static str {
+            "/nonexistent/fragile"
+        }
+
+        fn parse_span<'a>(&self, input: Span<'a>) -> ParseResult<'a, Self::Input<'a>> {
+            map(rest, |span: Span<'a>| *span.fragment())(input)
+        }
+
+        fn part1(&self, input: &Self::Input<'_>) -> Result<Answer, AocError> {
+            assert!(!input.contains('x'), "found an x");
+            Ok(input.len().into())
+        }
+
+        fn part2(&self, input: &Self::Input<'_>) -> Result<Answer, AocError> {
+            self.part1(input)
+        }
+    }
+
+    thread_local! {
+        static PANICS_SEEN: Cell<usize> = const { Cell::new(0) };
+    }
+
+    #[test]
+    fn reduce_finds_the_culprit() {
+        let split = Split::lines("1\n2\n3\n4\n5\n6\n7\n");
+        let shrunk = reduce(&split, &mut |text| text.contains('5'));
+
+        assert_eq!(shrunk.unwrap(), "5\n");
+    }
+
+    #[test]
+    fn blocks_keep_their_lines() {
+        let split = Split::blocks("$ ls\na\n$ cd b\n$ ls\nc\n", |line| line.starts_with('$'));
+
+        assert_eq!(split.pieces, ["$ ls\na", "$ cd b", "$ ls\nc"]);
+        assert_eq!((split.join)(&split.pieces[1..]), "$ cd b\n$ ls\nc\n");
+    }
+
+    #[test]
+    fn panics_shrink_without_reaching_the_hook() {
+        panic::set_hook(Box::new(|_| PANICS_SEEN.with(|n| n.set(n.get() + 1))));
+
+        let shrunk = shrink(&Fragile, Part::One, "a\nb\nx\nc\n", &Predicate::Panics);
+        let seen_while_shrinking = PANICS_SEEN.with(Cell::get);
+        let _ = panic::catch_unwind(|| panic!("after shrinking"));
+        let seen_after = PANICS_SEEN.with(Cell::get);
+        let _ = panic::take_hook();
+
+        assert_eq!(shrunk.as_deref(), Some("x\n"));
+        assert_eq!(seen_while_shrinking, 0);
+        assert_eq!(seen_after, 1);
+    }
+}
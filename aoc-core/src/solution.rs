@@ -0,0 +1,297 @@
+use crate::gen::Rng;
+use crate::input::Source;
+use crate::lint::Lint;
+use crate::shrink::Split;
+use crate::{finish, normalize, Answer, AocError, Dump, ParseResult, Span};
+use clap::ValueEnum;
+use serde::Serialize;
+use std::fmt;
+use std::io::BufRead;
+use std::time::{Duration, Instant};
+
+/// A single Advent of Code puzzle.
+///
+/// Each day crate implements this for a unit struct and gets the
+/// shared command line front-end from [`crate::Cli`] for free.
+pub trait Solution {
+    /// The parsed puzzle input. It has to serialize for `--dump-parsed`.
+    type Input<'a>: Serialize + fmt::Debug;
+
+    fn name(&self) -> &'static str;
+    fn year(&self) -> u32;
+    fn day(&self) -> u32;
+
+    /// The day crate's `CARGO_MANIFEST_DIR`, where its `input.txt` lives.
+    fn manifest_dir(&self) -> &'static str;
+
+    /// The day's top-level parser. It may leave input over; see
+    /// [`crate::finish`].
+    fn parse_span<'a>(&self, input: Span<'a>) -> ParseResult<'a, Self::Input<'a>>;
+
+    /// Parse all of `input`, rejecting anything but trailing whitespace
+    /// after what [`Solution::parse_span`] understood.
+    ///
+    /// `input` is expected to be [`crate::normalize`]d already, which
+    /// [`Runner::run`] takes care of.
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, AocError> {
+        self.parse_with(input, &Options::default())
+    }
+
+    /// Like [`Solution::parse`], but carry on past malformed lines and
+    /// report all of them at once. Days whose input is not line oriented
+    /// keep the default, which parses strictly.
+    fn parse_recovering<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, AocError> {
+        self.parse(input)
+    }
+
+    fn parse_with<'a>(
+        &self,
+        input: &'a str,
+        options: &Options,
+    ) -> Result<Self::Input<'a>, AocError> {
+        if options.recover {
+            return self.parse_recovering(input);
+        }
+
+        finish(input, options.lenient, |input| self.parse_span(input))
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer, AocError>;
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer, AocError>;
+
+    /// Solve `parts` in a single pass over `reader`, never holding the
+    /// whole input in memory. Days that cannot return `None`.
+    fn stream(
+        &self,
+        _source: &Source,
+        _reader: &mut dyn BufRead,
+        _parts: &[Part],
+    ) -> Option<Result<Vec<(Part, Answer)>, AocError>> {
+        None
+    }
+
+    /// A random, valid puzzle input of roughly `size` items, whatever an
+    /// item is for this day. Days without a generator return `None`.
+    fn generate(&self, _rng: &mut Rng, _size: usize) -> Option<String> {
+        None
+    }
+
+    /// `input` back in the puzzle format. Days whose parsed input loses
+    /// information return `None`.
+    fn print(&self, _input: &Self::Input<'_>) -> Option<String> {
+        None
+    }
+
+    /// `input`, already [`normalize`]d, in the day's canonical format:
+    /// parsed with [`Solution::parse_recovering`] so every malformed line
+    /// is reported at once and, where the day can, [`Solution::print`]ed
+    /// again. Days that cannot print their input but still have a
+    /// canonical layout override this.
+    fn format(&self, input: &str) -> Result<String, AocError> {
+        let parsed = self.parse_recovering(input)?;
+
+        Ok(self.print(&parsed).unwrap_or_else(|| input.to_owned()))
+    }
+
+    /// Everything suspicious about `input` that the parser lets through.
+    /// Days without rules find nothing.
+    fn lint(&self, _input: &str) -> Vec<Lint> {
+        vec![]
+    }
+
+    /// The ways [`crate::shrink`] may take `input` apart, coarsest first.
+    /// Lines, unless the day knows better.
+    fn splits(&self, input: &str) -> Vec<Split> {
+        vec![Split::lines(input)]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+pub enum Part {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::One => write!(f, "1"),
+            Self::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// How [`Runner::run`] should treat its input.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Options {
+    /// Use [`Solution::parse_recovering`] instead of [`Solution::parse`].
+    pub recover: bool,
+    /// Ignore input left over after the day's parser stops.
+    pub lenient: bool,
+}
+
+/// One part solved by [`Runner::run`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solved {
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+/// Everything [`Runner::run`] found, with the wall-clock time each
+/// phase took. Parsing includes [`normalize`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Run {
+    pub parse: Duration,
+    pub parts: Vec<Solved>,
+}
+
+impl Run {
+    pub fn answer(&self, part: Part) -> Option<&Answer> {
+        self.parts
+            .iter()
+            .find(|solved| solved.part == part)
+            .map(|solved| &solved.answer)
+    }
+}
+
+/// Object safe view of a [`Solution`].
+///
+/// Every day has its own `Input` type, so the multiplexer keeps days
+/// around as `&dyn Runner` instead.
+pub trait Runner: Sync {
+    fn name(&self) -> &'static str;
+    fn year(&self) -> u32;
+    fn day(&self) -> u32;
+    fn manifest_dir(&self) -> &'static str;
+
+    /// [`normalize`] and parse `input` once, then solve each of `parts`
+    /// in order, stopping at the first error.
+    fn run(&self, input: &str, parts: &[Part], options: &Options) -> Result<Run, AocError>;
+
+    /// [`normalize`] and parse `input`, and render what came out.
+    fn dump(&self, input: &str, options: &Options, format: Dump) -> Result<String, AocError>;
+
+    /// Like [`Runner::run`], but reading `reader` a piece at a time; see
+    /// [`Solution::stream`]. Parsing and solving are interleaved, so the
+    /// whole pass is reported as parse time.
+    fn stream(
+        &self,
+        source: &Source,
+        reader: &mut dyn BufRead,
+        parts: &[Part],
+    ) -> Option<Result<Run, AocError>>;
+
+    /// See [`Solution::generate`].
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String>;
+
+    /// See [`Solution::splits`].
+    fn splits(&self, input: &str) -> Vec<Split>;
+
+    /// [`normalize`] `input` and [`Solution::format`] it.
+    fn format(&self, input: &str) -> Result<String, AocError>;
+
+    /// [`normalize`] `input` and [`Solution::lint`] it, in input order,
+    /// with lints that point nowhere last.
+    fn lint(&self, input: &str) -> Vec<Lint>;
+}
+
+impl<S: Solution + Sync> Runner for S {
+    fn name(&self) -> &'static str {
+        Solution::name(self)
+    }
+
+    fn year(&self) -> u32 {
+        Solution::year(self)
+    }
+
+    fn day(&self) -> u32 {
+        Solution::day(self)
+    }
+
+    fn manifest_dir(&self) -> &'static str {
+        Solution::manifest_dir(self)
+    }
+
+    fn run(&self, input: &str, parts: &[Part], options: &Options) -> Result<Run, AocError> {
+        let start = Instant::now();
+        let input = normalize(input);
+        let parsed = self.parse_with(&input, options)?;
+        let parse = start.elapsed();
+
+        let parts = parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let answer = match part {
+                    Part::One => self.part1(&parsed)?,
+                    Part::Two => self.part2(&parsed)?,
+                };
+
+                Ok(Solved {
+                    part,
+                    answer,
+                    elapsed: start.elapsed(),
+                })
+            })
+            .collect::<Result<_, AocError>>()?;
+
+        Ok(Run { parse, parts })
+    }
+
+    fn dump(&self, input: &str, options: &Options, format: Dump) -> Result<String, AocError> {
+        let input = normalize(input);
+        let parsed = self.parse_with(&input, options)?;
+
+        Ok(format.render(&parsed))
+    }
+
+    fn stream(
+        &self,
+        source: &Source,
+        reader: &mut dyn BufRead,
+        parts: &[Part],
+    ) -> Option<Result<Run, AocError>> {
+        let start = Instant::now();
+        let answers = Solution::stream(self, source, reader, parts)?;
+        let parse = start.elapsed();
+
+        Some(answers.map(|answers| {
+            Run {
+                parse,
+                parts: answers
+                    .into_iter()
+                    .map(|(part, answer)| Solved {
+                        part,
+                        answer,
+                        elapsed: Duration::ZERO,
+                    })
+                    .collect(),
+            }
+        }))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        Solution::generate(self, rng, size)
+    }
+
+    fn splits(&self, input: &str) -> Vec<Split> {
+        Solution::splits(self, input)
+    }
+
+    fn format(&self, input: &str) -> Result<String, AocError> {
+        Solution::format(self, &normalize(input))
+    }
+
+    fn lint(&self, input: &str) -> Vec<Lint> {
+        let mut lints = Solution::lint(self, &normalize(input));
+        lints.sort_by_key(|lint| {
+            lint.location
+                .as_ref()
+                .map_or((u32::MAX, 0), |l| (l.line, l.column))
+        });
+        lints
+    }
+}
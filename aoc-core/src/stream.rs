@@ -0,0 +1,185 @@
+//! Drive nom's `streaming` parsers over a [`BufRead`], so inputs far
+//! larger than memory can be solved a piece at a time.
+
+use crate::input::{InputError, Source};
+use crate::{AocError, Diagnostic, Location};
+use nom::IResult;
+use std::io::BufRead;
+
+const BOM: &[u8] = b"\xef\xbb\xbf";
+
+/// Iterator over the items `parser` finds in `reader`.
+///
+/// Only unparsed input is buffered: whenever `parser` reports
+/// `Incomplete` what it already consumed is dropped and the buffer is
+/// topped up from `reader`. At the end of the input the last line is
+/// terminated and a blank line added, so line oriented parsers can tell
+/// their last item is done.
+pub struct Stream<R, P> {
+    source: Source,
+    reader: R,
+    parser: P,
+    buf: Vec<u8>,
+    start: usize,
+    /// Lines dropped from the front of `buf` so far.
+    line: u32,
+    /// Columns dropped from the front of the first line still in `buf`.
+    column: usize,
+    eof: bool,
+    done: bool,
+}
+
+impl<R: BufRead, P> Stream<R, P> {
+    pub fn new(source: &Source, reader: R, parser: P) -> Self {
+        Self {
+            source: source.clone(),
+            reader,
+            parser,
+            buf: vec![],
+            start: 0,
+            line: 0,
+            column: 0,
+            eof: false,
+            done: false,
+        }
+    }
+
+    fn refill(&mut self) -> Result<(), AocError> {
+        let consumed = &self.buf[..self.start];
+
+        match consumed.iter().rposition(|&b| b == b'\n') {
+            Some(i) => {
+                self.line += consumed.iter().filter(|&&b| b == b'\n').count() as u32;
+                self.column = consumed.len() - i - 1;
+            }
+            None => self.column += consumed.len(),
+        }
+
+        self.buf.drain(..self.start);
+        self.start = 0;
+
+        let chunk = self.reader.fill_buf().map_err(|error| {
+            AocError::Io(InputError::Io {
+                source: self.source.clone(),
+                error,
+            })
+        })?;
+
+        if chunk.is_empty() {
+            self.eof = true;
+            if !self.buf.ends_with(b"\n") {
+                self.buf.push(b'\n');
+            }
+            self.buf.push(b'\n');
+            return Ok(());
+        }
+
+        let len = chunk.len();
+        self.buf.extend_from_slice(chunk);
+        self.reader.consume(len);
+
+        if self.line == 0 && self.column == 0 && self.buf.starts_with(BOM) {
+            self.buf.drain(..BOM.len());
+        }
+
+        Ok(())
+    }
+
+    fn error(&self, offset: usize, message: String) -> AocError {
+        let mut location = Location::at(&self.buf, offset);
+
+        if location.line == 1 {
+            location.column += self.column;
+        }
+        location.line += self.line;
+
+        AocError::Parse(Diagnostic {
+            message,
+            location: Some(location),
+        })
+    }
+}
+
+impl<R, P, O> Iterator for Stream<R, P>
+where
+    R: BufRead,
+    P: FnMut(&[u8]) -> IResult<&[u8], O>,
+{
+    type Item = Result<O, AocError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            let input = &self.buf[self.start..];
+
+            if self.eof && input.iter().all(u8::is_ascii_whitespace) {
+                break;
+            }
+
+            let error = match (self.parser)(input) {
+                Ok((rest, _)) if rest.len() == input.len() => {
+                    self.error(self.start, "parser made no progress".to_owned())
+                }
+                Ok((rest, item)) => {
+                    self.start += input.len() - rest.len();
+                    return Some(Ok(item));
+                }
+                Err(nom::Err::Incomplete(_)) if !self.eof => match self.refill() {
+                    Ok(()) => continue,
+                    Err(e) => e,
+                },
+                Err(nom::Err::Incomplete(_)) => {
+                    self.error(self.buf.len(), "unexpected end of input".to_owned())
+                }
+                Err(nom::Err::Error(e) | nom::Err::Failure(e)) => self.error(
+                    self.buf.len() - e.input.len(),
+                    format!("unexpected input ({})", e.code.description()),
+                ),
+            };
+
+            self.done = true;
+            return Some(Err(error));
+        }
+
+        self.done = true;
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use nom::{
+        character::streaming::{self, line_ending},
+        sequence::terminated,
+    };
+    use std::io::BufReader;
+
+    fn number(input: &[u8]) -> IResult<&[u8], u32> {
+        terminated(streaming::u32, line_ending)(input)
+    }
+
+    #[test]
+    fn items_span_refills() {
+        // A tiny buffer makes every number straddle two reads.
+        let reader = BufReader::with_capacity(3, "\u{feff}12345\r\n678\n9".as_bytes());
+        let stream = Stream::new(&Source::Stdin, reader, number);
+
+        assert_eq!(
+            stream.collect::<Result<Vec<_>, _>>().unwrap(),
+            vec![12345, 678, 9]
+        );
+    }
+
+    #[test]
+    fn errors_are_located() {
+        let reader = BufReader::with_capacity(2, "1\n22\n3x3\n".as_bytes());
+        let err = Stream::new(&Source::Stdin, reader, number)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "parse error: unexpected input (CrLf) at 3:2"
+        );
+    }
+}
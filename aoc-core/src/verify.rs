@@ -0,0 +1,256 @@
+use crate::input::{self, InputError, Source};
+use crate::{Answer, AocError, Options, Part, Runner};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Name of the file holding a day's known answers, next to its input.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Directory in a day crate holding one sub-directory per puzzle example,
+/// each laid out like the crate itself: `input.txt` and `answers.toml`.
+pub const EXAMPLES_DIR: &str = "fixtures";
+
+/// The accepted answers for one day's real input.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Answers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<Answer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<Answer>,
+}
+
+impl Answers {
+    /// Load the answers recorded next to `input`. No answers file just
+    /// means nothing has been recorded yet.
+    pub fn load(input: &Path) -> Result<Self, InputError> {
+        let path = input.with_file_name(ANSWERS_FILE);
+
+        match fs::read_to_string(&path) {
+            Ok(text) => toml::from_str(&text).map_err(|error| InputError::Answers { path, error }),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(InputError::Io {
+                source: Source::File(path),
+                error,
+            }),
+        }
+    }
+
+    /// Record these answers next to `input`.
+    pub fn save(&self, input: &Path) -> io::Result<()> {
+        let text = toml::to_string(self).map_err(io::Error::other)?;
+
+        fs::write(input.with_file_name(ANSWERS_FILE), text)
+    }
+
+    pub fn get(&self, part: Part) -> Option<&Answer> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: Answer,
+        actual: Answer,
+    },
+    /// No answer recorded; this is what the solution came up with.
+    Missing(Answer),
+}
+
+impl Verdict {
+    fn new(expected: Option<&Answer>, actual: Answer) -> Self {
+        match expected {
+            None => Self::Missing(actual),
+            Some(expected) if *expected == actual => Self::Pass,
+            Some(expected) => Self::Fail {
+                expected: expected.clone(),
+                actual,
+            },
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Pass => write!(f, "pass"),
+            Self::Fail { expected, actual } => {
+                write!(f, "FAIL: expected {}, got {}", expected, actual)
+            }
+            Self::Missing(actual) => write!(f, "missing, got {}", actual),
+        }
+    }
+}
+
+/// Solve both parts of `solution` on the input from `source` and check
+/// them against the answers recorded next to it.
+pub fn verify(solution: &dyn Runner, source: &Source) -> Result<Vec<(Part, Verdict)>, AocError> {
+    let answers = match source {
+        Source::File(path) => Answers::load(path)?,
+        Source::Stdin => Answers::default(),
+    };
+    let input = input::read(source)?;
+    let run = solution.run(&input, &[Part::One, Part::Two], &Options::default())?;
+
+    Ok(run
+        .parts
+        .into_iter()
+        .map(|solved| {
+            let verdict = Verdict::new(answers.get(solved.part), solved.answer);
+            (solved.part, verdict)
+        })
+        .collect())
+}
+
+/// Panic unless every recorded answer for `solution` still comes out
+/// of its real input. Days without an input are skipped.
+pub fn assert_answers(solution: &dyn Runner) {
+    let source = match input::resolve(solution, None) {
+        Ok(source) => source,
+        Err(e @ InputError::NotFound { .. }) => {
+            eprintln!("skipping {}: {}", solution.name(), e);
+            return;
+        }
+        Err(e) => panic!("{}", e),
+    };
+
+    let verdicts = verify(solution, &source).unwrap_or_else(|e| panic!("{}", e));
+
+    for (part, verdict) in verdicts {
+        assert!(
+            !matches!(verdict, Verdict::Fail { .. }),
+            "{} part {}: {}",
+            solution.name(),
+            part,
+            verdict
+        );
+    }
+}
+
+/// The `input.txt` of every example of `solution`, in name order.
+pub fn examples(solution: &dyn Runner) -> io::Result<Vec<PathBuf>> {
+    let dir = Path::new(solution.manifest_dir()).join(EXAMPLES_DIR);
+
+    let mut inputs = match fs::read_dir(dir) {
+        Ok(entries) => entries
+            .map(|entry| Ok(entry?.path().join("input.txt")))
+            .collect::<io::Result<Vec<_>>>()?,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => vec![],
+        Err(e) => return Err(e),
+    };
+    inputs.sort();
+
+    Ok(inputs)
+}
+
+/// Panic unless `solution` comes up with the recorded answer for every
+/// example. Only the parts an example has answers for are run.
+pub fn assert_examples(solution: &dyn Runner) {
+    let inputs = examples(solution).unwrap_or_else(|e| panic!("{}", e));
+
+    assert!(
+        !inputs.is_empty(),
+        "{} has no {}, save some with `aoc examples`",
+        solution.name(),
+        EXAMPLES_DIR
+    );
+
+    for path in inputs {
+        let answers = Answers::load(&path).unwrap_or_else(|e| panic!("{}", e));
+        let parts = [Part::One, Part::Two]
+            .into_iter()
+            .filter(|&part| answers.get(part).is_some())
+            .collect::<Vec<_>>();
+        let input = input::read(&Source::File(path.clone())).unwrap_or_else(|e| panic!("{}", e));
+        let run = solution
+            .run(&input, &parts, &Options::default())
+            .unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+
+        for solved in run.parts {
+            let verdict = Verdict::new(answers.get(solved.part), solved.answer);
+
+            assert_eq!(
+                verdict,
+                Verdict::Pass,
+                "{} part {}",
+                path.display(),
+                solved.part
+            );
+        }
+    }
+}
+
+/// Generate a `#[test]` checking `$solution` against its `answers.toml`.
+#[macro_export]
+macro_rules! verify_answers {
+    ($solution:expr) => {
+        #[test]
+        fn answers_match() {
+            $crate::verify::assert_answers(&$solution);
+        }
+    };
+}
+
+/// Generate a `#[test]` checking `$solution` against its examples,
+/// with the attribute given before it, if any, such as `#[ignore]`.
+#[macro_export]
+macro_rules! verify_examples {
+    (#[$attr:meta] $solution:expr) => {
+        #[test]
+        #[$attr]
+        fn examples_match() {
+            $crate::verify::assert_examples(&$solution);
+        }
+    };
+    ($solution:expr) => {
+        #[test]
+        fn examples_match() {
+            $crate::verify::assert_examples(&$solution);
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn answers_parse() {
+        let answers: Answers = toml::from_str("part1 = 24000\npart2 = \"MCD\"\n").unwrap();
+
+        assert_eq!(answers.get(Part::One), Some(&Answer::Number(24000)));
+        assert_eq!(answers.get(Part::Two), Some(&Answer::from("MCD")));
+        assert!(toml::from_str::<Answers>("part3 = 1\n").is_err());
+        assert_eq!(
+            toml::to_string(&Answers {
+                part2: None,
+                ..answers
+            })
+            .unwrap(),
+            "part1 = 24000\n"
+        );
+    }
+
+    #[test]
+    fn verdicts_work() {
+        let expected = Answer::from(1u32);
+
+        assert_eq!(Verdict::new(Some(&expected), 1u32.into()), Verdict::Pass);
+        assert_eq!(
+            Verdict::new(Some(&expected), 2u32.into()).to_string(),
+            "FAIL: expected 1, got 2"
+        );
+        assert_eq!(
+            Verdict::new(None, 2u32.into()).to_string(),
+            "missing, got 2"
+        );
+    }
+}
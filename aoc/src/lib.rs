@@ -0,0 +1,712 @@
+mod puzzle;
+mod registry;
+mod scaffold;
+mod table;
+
+use aoc_core::fetch::{Fetched, Fetcher};
+use aoc_core::gen::Rng;
+use aoc_core::input::{self, Source};
+use aoc_core::lint::Severity;
+use aoc_core::output::{self, Format, Record};
+use aoc_core::shrink::{self, Predicate};
+use aoc_core::verify::{self, Verdict};
+use aoc_core::{Answer, AocError, Options, Part, Run, Runner};
+use clap::{Args, Parser, Subcommand};
+use registry::{DayId, Selector};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "aoc",
+    author = "Felipe Balbi <felipe@balbi.sh>",
+    about = "Run any Advent of Code solution in this workspace",
+    version = "0.1.0"
+)]
+pub struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// List every registered day.
+    List,
+
+    /// Run one, several or all days.
+    Run {
+        #[arg(required = true, help = "Days to run, as `8` or `2022/8`, or `all`.")]
+        days: Vec<Selector>,
+
+        #[arg(short, long, help = "Run only this part.")]
+        part: Option<Part>,
+
+        #[arg(
+            short,
+            long,
+            value_name = "PATH",
+            help = "Read the puzzle input from PATH, or `-` for stdin. Needs a single day."
+        )]
+        input: Option<PathBuf>,
+
+        #[arg(
+            long,
+            help = "Keep parsing past malformed lines and report all of them."
+        )]
+        recover: bool,
+
+        #[arg(long, help = "Ignore input left over after parsing stops.")]
+        lenient: bool,
+
+        #[arg(long, help = "Add parse and solve times to the summary.")]
+        time: bool,
+
+        #[arg(long, value_enum, default_value_t, help = "How to print answers.")]
+        format: Format,
+    },
+
+    /// Check days against the answers recorded in their `answers.toml`.
+    Verify {
+        #[arg(
+            default_value = "all",
+            help = "Days to verify, as `8` or `2022/8`, or `all`."
+        )]
+        days: Vec<Selector>,
+    },
+
+    /// Generate a random, valid puzzle input for a day.
+    Gen {
+        #[arg(help = "Day to generate an input for, as `8` or `2022/8`.")]
+        day: DayId,
+
+        #[arg(
+            long,
+            default_value_t = 100,
+            help = "Roughly how many items to generate."
+        )]
+        size: usize,
+
+        #[arg(long, default_value_t = 0, help = "Seed for the random generator.")]
+        seed: u64,
+
+        #[arg(
+            short,
+            long,
+            value_name = "PATH",
+            help = "Write the input to PATH instead of stdout."
+        )]
+        output: Option<PathBuf>,
+    },
+
+    /// Shrink a failing input to the smallest one that still fails.
+    Shrink {
+        #[arg(help = "Day the input is for, as `8` or `2022/8`.")]
+        day: DayId,
+
+        #[arg(help = "The failing input, or `-` for stdin.")]
+        input: PathBuf,
+
+        #[arg(short, long, default_value = "1", help = "The part to run.")]
+        part: Part,
+
+        #[command(flatten)]
+        predicate: PredicateArgs,
+
+        #[arg(
+            short,
+            long,
+            value_name = "PATH",
+            help = "Write the smallest input to PATH instead of stdout."
+        )]
+        output: Option<PathBuf>,
+    },
+
+    /// Rewrite an input in the day's canonical format.
+    Fmt {
+        #[arg(help = "Day the input is for, as `8` or `2022/8`.")]
+        day: DayId,
+
+        #[arg(help = "The input to rewrite in place, or `-` to format stdin to stdout.")]
+        file: PathBuf,
+
+        #[arg(
+            long,
+            help = "Change nothing, but exit with 1 if FILE is not canonical."
+        )]
+        check: bool,
+    },
+
+    /// Point out inputs that parse, but would give a wrong answer or none.
+    ///
+    /// Day 2 has no rules: every round that parses is one both parts can
+    /// score.
+    Lint {
+        #[arg(
+            default_value = "all",
+            help = "Days to lint, as `8` or `2022/8`, or `all`."
+        )]
+        days: Vec<Selector>,
+
+        #[arg(
+            short,
+            long,
+            value_name = "PATH",
+            help = "Lint PATH instead, or `-` for stdin. Needs a single day."
+        )]
+        input: Option<PathBuf>,
+
+        #[arg(
+            long,
+            value_enum,
+            default_value_t = Severity::Info,
+            help = "Only show lints at least this severe."
+        )]
+        severity: Severity,
+    },
+
+    /// Create and register a crate for a new day.
+    New {
+        #[arg(help = "Year of the puzzle.")]
+        year: u32,
+
+        #[arg(help = "Day of the puzzle.")]
+        day: u32,
+    },
+
+    /// Save the examples on a downloaded puzzle page as a day's fixtures.
+    ///
+    /// Only the page's first example block is saved. A part two with a
+    /// larger example of its own gets its answer recorded against it, so
+    /// check those fixtures by hand.
+    Examples {
+        #[arg(help = "Day the page is for, as `8` or `2022/8`.")]
+        day: DayId,
+
+        #[arg(help = "The puzzle page, saved as HTML.")]
+        page: PathBuf,
+
+        #[arg(long, help = "Replace the day's existing fixtures.")]
+        force: bool,
+    },
+
+    /// Download puzzle inputs into the local cache, once.
+    Fetch {
+        #[arg(
+            default_value = "all",
+            help = "Days to fetch, as `8` or `2022/8`, or `all`."
+        )]
+        days: Vec<Selector>,
+    },
+}
+
+/// How an input has to fail to be kept while shrinking.
+#[derive(Args, Debug)]
+#[group(required = true, multiple = false)]
+struct PredicateArgs {
+    #[arg(long, help = "Parsing or solving panics.")]
+    panics: bool,
+
+    #[arg(
+        long,
+        value_name = "ANSWER",
+        help = "The part solves, but to something other than ANSWER."
+    )]
+    differs: Option<Answer>,
+
+    #[arg(long, help = "Parsing fails with the same message as for INPUT.")]
+    parse_error: bool,
+}
+
+impl PredicateArgs {
+    fn predicate(&self) -> Predicate {
+        match &self.differs {
+            Some(expected) => Predicate::Differs(expected.clone()),
+            None if self.panics => Predicate::Panics,
+            None => Predicate::ParseError,
+        }
+    }
+}
+
+impl Cli {
+    pub fn run(&self) {
+        match &self.command {
+            Command::List => list(),
+            Command::Run {
+                days,
+                part,
+                input,
+                recover,
+                lenient,
+                time,
+                format,
+            } => {
+                let options = Options {
+                    recover: *recover,
+                    lenient: *lenient,
+                };
+                run(days, *part, input.as_deref(), &options, *time, *format)
+            }
+            Command::Verify { days } => verify(days),
+            Command::Gen {
+                day,
+                size,
+                seed,
+                output,
+            } => gen(*day, *size, *seed, output.as_deref()),
+            Command::Shrink {
+                day,
+                input,
+                part,
+                predicate,
+                output,
+            } => shrink(
+                *day,
+                input,
+                *part,
+                &predicate.predicate(),
+                output.as_deref(),
+            ),
+            Command::Fmt { day, file, check } => fmt(*day, file, *check),
+            Command::Lint {
+                days,
+                input,
+                severity,
+            } => lint(days, input.as_deref(), *severity),
+            Command::New { year, day } => new(*year, *day),
+            Command::Examples { day, page, force } => examples(*day, page, *force),
+            Command::Fetch { days } => fetch(days),
+        }
+    }
+}
+
+fn list() {
+    let rows = registry::DAYS
+        .iter()
+        .map(|d| {
+            vec![
+                d.year().to_string(),
+                d.day().to_string(),
+                d.name().to_owned(),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    println!("{}", table::render(&["Year", "Day", "Name"], &rows));
+}
+
+fn select(selectors: &[Selector]) -> Vec<&'static dyn Runner> {
+    registry::select(selectors).unwrap_or_else(|e| {
+        eprintln!("error: {}", e);
+        std::process::exit(2);
+    })
+}
+
+fn run(
+    selectors: &[Selector],
+    part: Option<Part>,
+    explicit: Option<&Path>,
+    options: &Options,
+    time: bool,
+    format: Format,
+) {
+    let days = select(selectors);
+
+    if explicit.is_some() && days.len() != 1 {
+        eprintln!("error: --input needs exactly one day");
+        std::process::exit(2);
+    }
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![Part::One, Part::Two],
+    };
+
+    let mut rows = vec![];
+    let mut exit_code = None;
+
+    if format == Format::Csv {
+        println!("{}", output::CSV_HEADER);
+    }
+
+    for day in days {
+        let mut file = day.name().to_owned();
+        let result = input::resolve(day, explicit)
+            .map_err(AocError::from)
+            .and_then(|source| {
+                file = source.to_string();
+                let input = input::read(&source)?;
+                day.run(&input, &parts, options)
+            });
+
+        if let Err(e) = &result {
+            exit_code.get_or_insert(e.exit_code());
+        }
+
+        match format {
+            Format::Text => {
+                if let Err(e) = &result {
+                    eprintln!("{}", e.render(&file));
+                }
+                rows.push(summary_row(day, &parts, &result, time));
+            }
+            Format::Json => println!("{}", Record::new(day, &result).to_json()),
+            Format::Csv => {
+                for row in Record::new(day, &result).to_csv() {
+                    println!("{}", row);
+                }
+            }
+        }
+    }
+
+    if format != Format::Text {
+        if let Some(code) = exit_code {
+            std::process::exit(code);
+        }
+        return;
+    }
+
+    let mut header = vec!["Day", "Name"];
+    header.extend(parts.iter().map(|p| match p {
+        Part::One => "Part 1",
+        Part::Two => "Part 2",
+    }));
+
+    if time {
+        header.extend(["Parse", "Solve"]);
+    }
+
+    println!("{}", table::render(&header, &rows));
+
+    if let Some(code) = exit_code {
+        std::process::exit(code);
+    }
+}
+
+fn summary_row(
+    day: &dyn Runner,
+    parts: &[Part],
+    result: &Result<Run, AocError>,
+    time: bool,
+) -> Vec<String> {
+    let mut row = vec![day.day().to_string(), day.name().to_owned()];
+
+    row.extend(parts.iter().map(|&part| {
+        match result {
+            Ok(run) => run
+                .answer(part)
+                .map(|answer| answer.to_string())
+                .unwrap_or_else(|| "-".to_owned()),
+            Err(_) => "error".to_owned(),
+        }
+    }));
+
+    if time {
+        match result {
+            Ok(run) => {
+                let solve = run
+                    .parts
+                    .iter()
+                    .map(|solved| solved.elapsed)
+                    .sum::<Duration>();
+                row.extend([format!("{:.2?}", run.parse), format!("{:.2?}", solve)]);
+            }
+            Err(_) => row.extend(["-".to_owned(), "-".to_owned()]),
+        }
+    }
+
+    row
+}
+
+fn verify(selectors: &[Selector]) {
+    let mut rows = vec![];
+    let mut exit_code = None;
+
+    for day in select(selectors) {
+        let mut file = day.name().to_owned();
+        let result = input::resolve(day, None)
+            .map_err(AocError::from)
+            .and_then(|source| {
+                file = source.to_string();
+                verify::verify(day, &source)
+            });
+
+        let mut row = vec![day.day().to_string(), day.name().to_owned()];
+
+        match result {
+            Ok(verdicts) => {
+                if verdicts
+                    .iter()
+                    .any(|(_, v)| matches!(v, Verdict::Fail { .. }))
+                {
+                    exit_code.get_or_insert(1);
+                }
+
+                row.extend(verdicts.iter().map(|(_, v)| v.to_string()));
+            }
+            Err(e) => {
+                eprintln!("{}", e.render(&file));
+                exit_code.get_or_insert(e.exit_code());
+                row.extend(["error".to_owned(), "error".to_owned()]);
+            }
+        }
+
+        rows.push(row);
+    }
+
+    println!(
+        "{}",
+        table::render(&["Day", "Name", "Part 1", "Part 2"], &rows)
+    );
+
+    if let Some(code) = exit_code {
+        std::process::exit(code);
+    }
+}
+
+fn find(day: DayId) -> &'static dyn Runner {
+    registry::find(day).unwrap_or_else(|e| {
+        eprintln!("error: {}", e);
+        std::process::exit(2);
+    })
+}
+
+/// Print `text`, or write it to `output` if given.
+fn emit(text: &str, output: Option<&Path>) {
+    match output {
+        Some(path) => {
+            if let Err(e) = std::fs::write(path, text) {
+                eprintln!("error: cannot write {}: {}", path.display(), e);
+                std::process::exit(3);
+            }
+        }
+        None => print!("{}", text),
+    }
+}
+
+fn gen(day: DayId, size: usize, seed: u64, output: Option<&Path>) {
+    let day = find(day);
+
+    let input = day.generate(&mut Rng::new(seed), size).unwrap_or_else(|| {
+        eprintln!("error: {} has no input generator", day.name());
+        std::process::exit(2);
+    });
+
+    emit(&input, output);
+}
+
+/// Read `path`, or stdin for `-`, exiting on failure.
+fn read(day: &dyn Runner, path: &Path) -> (Source, String) {
+    input::resolve(day, Some(path))
+        .and_then(|source| input::read(&source).map(|input| (source, input)))
+        .unwrap_or_else(|e| {
+            let e = AocError::from(e);
+            eprintln!("{}", e.render(&path.display().to_string()));
+            std::process::exit(e.exit_code());
+        })
+}
+
+fn shrink(day: DayId, path: &Path, part: Part, predicate: &Predicate, output: Option<&Path>) {
+    let day = find(day);
+    let (_, input) = read(day, path);
+
+    match shrink::shrink(day, part, &input, predicate) {
+        Some(smallest) => emit(&smallest, output),
+        None => {
+            eprintln!("error: {} does not fail that way", path.display());
+            std::process::exit(1);
+        }
+    }
+}
+
+fn fmt(day: DayId, path: &Path, check: bool) {
+    let day = find(day);
+    let (source, input) = read(day, path);
+
+    let canonical = day.format(&input).unwrap_or_else(|e| {
+        eprintln!("{}", e.render(&source.to_string()));
+        std::process::exit(e.exit_code());
+    });
+
+    if check {
+        if canonical != input {
+            eprintln!("{} is not canonical", source);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    match source {
+        Source::Stdin => print!("{}", canonical),
+        Source::File(path) if canonical != input => emit(&canonical, Some(&path)),
+        Source::File(_) => {}
+    }
+}
+
+fn lint(selectors: &[Selector], explicit: Option<&Path>, severity: Severity) {
+    let days = select(selectors);
+
+    if explicit.is_some() && days.len() != 1 {
+        eprintln!("error: --input needs exactly one day");
+        std::process::exit(2);
+    }
+
+    let mut exit_code = None;
+
+    for day in days {
+        let mut file = day.name().to_owned();
+        let result = input::resolve(day, explicit).and_then(|source| {
+            file = source.to_string();
+            input::read(&source)
+        });
+
+        let input = match result {
+            Ok(input) => input,
+            Err(e) => {
+                let e = AocError::from(e);
+                eprintln!("{}", e.render(&file));
+                exit_code.get_or_insert(e.exit_code());
+                continue;
+            }
+        };
+
+        let lints = day
+            .lint(&input)
+            .into_iter()
+            .filter(|lint| lint.severity >= severity)
+            .collect::<Vec<_>>();
+
+        for lint in &lints {
+            eprintln!("{}", lint.render(&file));
+        }
+
+        let count = |severity| lints.iter().filter(|l| l.severity == severity).count();
+        let (errors, warnings) = (count(Severity::Error), count(Severity::Warning));
+
+        if errors > 0 {
+            exit_code.get_or_insert(1);
+        }
+
+        println!(
+            "{}: {} error{}, {} warning{}",
+            day.name(),
+            errors,
+            if errors == 1 { "" } else { "s" },
+            warnings,
+            if warnings == 1 { "" } else { "s" },
+        );
+    }
+
+    if let Some(code) = exit_code {
+        std::process::exit(code);
+    }
+}
+
+fn new(year: u32, day: u32) {
+    if !(1..=25).contains(&day) {
+        eprintln!("error: there is no day {}, puzzles run from 1 to 25", day);
+        std::process::exit(2);
+    }
+
+    let workspace = std::env::current_dir()
+        .ok()
+        .and_then(|dir| scaffold::workspace(&dir))
+        .unwrap_or_else(|| {
+            eprintln!("error: not inside a cargo workspace");
+            std::process::exit(2);
+        });
+
+    match scaffold::new(&workspace, year, day) {
+        Ok(dir) => println!("created {} and registered it with aoc", dir.display()),
+        Err(e) => {
+            eprintln!("error: {}", e);
+            std::process::exit(3);
+        }
+    }
+}
+
+/// Replace `dir` with one fixture per example.
+fn save_examples(dir: &Path, examples: &[puzzle::Example]) -> std::io::Result<()> {
+    if dir.exists() {
+        std::fs::remove_dir_all(dir)?;
+    }
+
+    for (i, example) in examples.iter().enumerate() {
+        let input = dir.join(format!("example{}", i + 1)).join("input.txt");
+        std::fs::create_dir_all(input.parent().unwrap_or(dir))?;
+        std::fs::write(&input, &example.input)?;
+        example.answers.save(&input)?;
+
+        let answers = [Part::One, Part::Two]
+            .into_iter()
+            .filter_map(|part| {
+                let answer = example.answers.get(part)?;
+                Some(format!("part {}: {}", part, answer))
+            })
+            .collect::<Vec<_>>();
+        println!("{} ({})", input.display(), answers.join(", "));
+    }
+
+    Ok(())
+}
+
+fn examples(day: DayId, page: &Path, force: bool) {
+    let day = find(day);
+
+    let html = std::fs::read_to_string(page).unwrap_or_else(|e| {
+        eprintln!("error: cannot read {}: {}", page.display(), e);
+        std::process::exit(3);
+    });
+
+    let examples = puzzle::examples(&html);
+    if examples.is_empty() {
+        eprintln!(
+            "error: found no examples with answers in {}",
+            page.display()
+        );
+        std::process::exit(1);
+    }
+
+    let dir = Path::new(day.manifest_dir()).join(verify::EXAMPLES_DIR);
+    if dir.exists() && !force {
+        eprintln!(
+            "error: {} already exists, pass --force to replace it",
+            dir.display()
+        );
+        std::process::exit(2);
+    }
+
+    if let Err(e) = save_examples(&dir, &examples) {
+        eprintln!("error: cannot write {}: {}", dir.display(), e);
+        std::process::exit(3);
+    }
+}
+
+fn fetch(selectors: &[Selector]) {
+    let days = select(selectors);
+
+    let fetcher = Fetcher::from_env().unwrap_or_else(|e| {
+        eprintln!("error: {}", e);
+        std::process::exit(3);
+    });
+
+    let mut exit_code = None;
+
+    for day in days {
+        match fetcher.fetch(day.year(), day.day()) {
+            Ok(fetched @ Fetched::Cached(_)) => {
+                println!("{}: cached in {}", day.name(), fetched.path().display())
+            }
+            Ok(fetched @ Fetched::Downloaded(_)) => {
+                println!("{}: downloaded to {}", day.name(), fetched.path().display())
+            }
+            Err(e) => {
+                eprintln!("error: {}: {}", day.name(), e);
+                exit_code.get_or_insert(3);
+            }
+        }
+    }
+
+    if let Some(code) = exit_code {
+        std::process::exit(code);
+    }
+}
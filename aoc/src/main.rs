@@ -0,0 +1,6 @@
+use aoc::Cli;
+use clap::Parser;
+
+fn main() {
+    Cli::parse().run();
+}
@@ -0,0 +1,193 @@
+use aoc_core::verify::Answers;
+use aoc_core::{Answer, Part};
+
+/// An example input from a puzzle page, with the answers the page gives
+/// for it.
+#[derive(Debug, Default, PartialEq)]
+pub struct Example {
+    pub input: String,
+    pub answers: Answers,
+}
+
+/// Every piece of `html` from an `open` to the next `close`, with the
+/// offset it starts at.
+fn between<'a>(html: &'a str, open: &str, close: &str) -> Vec<(usize, &'a str)> {
+    let mut found = vec![];
+    let mut at = 0;
+
+    while let Some(start) = html[at..].find(open).map(|i| at + i + open.len()) {
+        let Some(end) = html[start..].find(close).map(|i| start + i) else {
+            break;
+        };
+        found.push((start, &html[start..end]));
+        at = end + close.len();
+    }
+
+    found
+}
+
+/// Drop the tags in `html` and decode the entities puzzle pages use.
+fn text(html: &str) -> String {
+    let mut text = String::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        text.push_str(&rest[..start]);
+        rest = rest[start..]
+            .find('>')
+            .map_or("", |end| &rest[start + end + 1..]);
+    }
+    text.push_str(rest);
+
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// The last emphasized code in `html`, which is how pages give the
+/// answer for an example.
+fn emphasized(html: &str) -> Option<Answer> {
+    let mut found = between(html, "<code><em>", "</em></code>");
+    found.extend(between(html, "<em><code>", "</code></em>"));
+
+    found
+        .into_iter()
+        .max_by_key(|&(at, _)| at)
+        .map(|(_, answer)| text(answer).trim().parse().unwrap_or_else(|e| match e {}))
+}
+
+fn record(examples: &mut Vec<Example>, input: &str, part: Part, answer: Answer) {
+    // Blocks may or may not end in a newline, inline examples never do.
+    let input = format!("{}\n", input.trim_end_matches('\n'));
+    let index = match examples.iter().position(|e| e.input == input) {
+        Some(index) => index,
+        None => {
+            examples.push(Example {
+                input,
+                ..Default::default()
+            });
+            examples.len() - 1
+        }
+    };
+
+    let answers = &mut examples[index].answers;
+    match part {
+        Part::One => answers.part1 = Some(answer),
+        Part::Two => answers.part2 = Some(answer),
+    }
+}
+
+/// Pull the examples out of a saved puzzle page.
+///
+/// The first `<pre><code>` block is the example both parts talk about,
+/// and the last emphasized answer of each part's article is its answer
+/// there. Later blocks only show steps along the way, so they are left
+/// out. Some days also list more examples inline, as list items starting
+/// with the input in `<code>` and ending with the answer.
+///
+/// A part that brings in an example of its own in a later block, such as
+/// a larger one in part two, is not told apart from those steps. Its
+/// answer is recorded against the first block, and that fixture has to
+/// be fixed by hand.
+pub fn examples(html: &str) -> Vec<Example> {
+    let mut examples = vec![];
+    let main = between(html, "<pre><code>", "</code></pre>")
+        .first()
+        .map(|&(_, block)| text(block));
+    let articles = between(html, "<article", "</article>");
+
+    for (part, &(_, article)) in [Part::One, Part::Two].iter().zip(&articles) {
+        let items = between(article, "<li>", "</li>");
+
+        // Answers in list items belong to those items, not to the example.
+        let outside = items
+            .iter()
+            .fold(article.to_owned(), |outside, &(_, item)| {
+                outside.replacen(item, "", 1)
+            });
+
+        if let (Some(input), Some(answer)) = (&main, emphasized(&outside)) {
+            record(&mut examples, input, *part, answer);
+        }
+
+        for &(_, item) in &items {
+            let Some(&(_, input)) = between(item, "<code>", "</code>").first() else {
+                continue;
+            };
+            let answer = match emphasized(item) {
+                Some(answer) if item.trim_start().starts_with("<code>") => answer,
+                _ => continue,
+            };
+
+            record(&mut examples, &text(input), *part, answer);
+        }
+    }
+
+    examples
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn examples_are_extracted() {
+        let html = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Test ---</h2>
+<p>For example:</p>
+<pre><code>1 &lt; 2
+<em>3</em></code></pre>
+<p>The first line adds up to <code><em>3</em></code>.</p>
+<ul>
+<li><code>4</code> becomes <code><em>5</em></code>.</li>
+<li>The second line has <code>3</code> in it, <code><em>once</em></code>.</li>
+</ul>
+<p>Then, the answer is <em><code>6</code></em>.</p>
+</article>
+<article class="day-desc"><h2>--- Part Two ---</h2>
+<pre><code>2
+</code></pre>
+<ul><li><code>4</code> becomes <code><em>7</em></code>.</li></ul>
+<p>Now it is <code><em>CMZ</em></code>.</p>
+</article>
+</main>"#;
+
+        assert_eq!(
+            examples(html),
+            vec![
+                Example {
+                    input: "1 < 2\n3\n".to_owned(),
+                    answers: Answers {
+                        part1: Some(Answer::Number(6)),
+                        part2: Some(Answer::from("CMZ")),
+                    },
+                },
+                Example {
+                    input: "4\n".to_owned(),
+                    answers: Answers {
+                        part1: Some(Answer::Number(5)),
+                        part2: Some(Answer::Number(7)),
+                    },
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn steps_on_a_saved_page_are_left_out() {
+        let html = include_str!("../tests/pages/2022-5.html");
+
+        assert_eq!(
+            examples(html),
+            vec![Example {
+                input: include_str!("../../aoc2022d5/fixtures/example1/input.txt").to_owned(),
+                answers: Answers {
+                    part1: Some(Answer::from("CMZ")),
+                    part2: Some(Answer::from("MCD")),
+                },
+            }]
+        );
+    }
+}
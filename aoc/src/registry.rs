@@ -0,0 +1,164 @@
+use aoc_core::Runner;
+
+/// Every day linked into the multiplexer, ordered by year and day.
+/// `aoc new` adds to this list, so keep one day per line.
+pub static DAYS: &[&dyn Runner] = &[
+    &aoc2022d1::Day1,
+    &aoc2022d2::Day2,
+    &aoc2022d3::Day3,
+    &aoc2022d4::Day4,
+    &aoc2022d5::Day5,
+    &aoc2022d6::Day6,
+    &aoc2022d7::Day7,
+    &aoc2022d8::Day8,
+];
+
+/// A day on the command line: `8` for the only registered day 8, or
+/// `2022/8` when more than one year has one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DayId {
+    pub year: Option<u32>,
+    pub day: u32,
+}
+
+impl DayId {
+    fn matches(&self, runner: &dyn Runner) -> bool {
+        runner.day() == self.day && self.year.is_none_or(|year| runner.year() == year)
+    }
+}
+
+impl std::fmt::Display for DayId {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.year {
+            Some(year) => write!(f, "{}/{}", year, self.day),
+            None => write!(f, "{}", self.day),
+        }
+    }
+}
+
+impl std::str::FromStr for DayId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || format!("expected a day like `8` or `2022/8`, got `{}`", s);
+
+        match s.split_once('/') {
+            Some((year, day)) => Ok(Self {
+                year: Some(year.parse().map_err(|_| error())?),
+                day: day.parse().map_err(|_| error())?,
+            }),
+            None => Ok(Self {
+                year: None,
+                day: s.parse().map_err(|_| error())?,
+            }),
+        }
+    }
+}
+
+/// The registered day `id` names. A bare day number is only enough when
+/// a single year has that day.
+pub fn find(id: DayId) -> Result<&'static dyn Runner, String> {
+    let found = DAYS
+        .iter()
+        .copied()
+        .filter(|d| id.matches(*d))
+        .collect::<Vec<_>>();
+
+    match found[..] {
+        [day] => Ok(day),
+        [] => Err(format!("day {} is not registered", id)),
+        _ => Err(format!(
+            "day {} is registered for several years, pick one of {}",
+            id,
+            found
+                .iter()
+                .map(|d| format!("{}/{}", d.year(), d.day()))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Selector {
+    All,
+    Day(DayId),
+}
+
+impl std::str::FromStr for Selector {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(Self::All),
+            _ => s
+                .parse()
+                .map(Self::Day)
+                .map_err(|e| format!("{}, or `all`", e)),
+        }
+    }
+}
+
+/// Resolve `selectors` into registered days, in the order given and
+/// without duplicates.
+pub fn select(selectors: &[Selector]) -> Result<Vec<&'static dyn Runner>, String> {
+    let mut days: Vec<&'static dyn Runner> = vec![];
+
+    for selector in selectors {
+        let found = match selector {
+            Selector::All => DAYS.to_vec(),
+            Selector::Day(id) => vec![find(*id)?],
+        };
+
+        for d in found {
+            if !days
+                .iter()
+                .any(|other| (other.year(), other.day()) == (d.year(), d.day()))
+            {
+                days.push(d);
+            }
+        }
+    }
+
+    Ok(days)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn days_are_ordered() {
+        let days = DAYS.iter().map(|d| (d.year(), d.day())).collect::<Vec<_>>();
+        let mut sorted = days.clone();
+        sorted.sort();
+        sorted.dedup();
+
+        assert_eq!(days, sorted);
+        assert_eq!(days.iter().filter(|&&(year, _)| year == 2022).count(), 8);
+    }
+
+    #[test]
+    fn select_works() {
+        let days = select(&[Selector::Day("5".parse().unwrap()), Selector::All]).unwrap();
+
+        assert_eq!(days.len(), DAYS.len());
+        assert_eq!(days[0].day(), 5);
+        assert!(select(&[Selector::Day("26".parse().unwrap())]).is_err());
+        assert!(select(&[Selector::Day("2015/5".parse().unwrap())]).is_err());
+    }
+
+    #[test]
+    fn day_ids_parse() {
+        assert_eq!(
+            "2022/8".parse(),
+            Ok(DayId {
+                year: Some(2022),
+                day: 8
+            })
+        );
+        assert_eq!("8".parse(), Ok(DayId { year: None, day: 8 }));
+        assert!("2022/".parse::<DayId>().is_err());
+        assert_eq!(find("2022/8".parse().unwrap()).unwrap().name(), "aoc2022d8");
+    }
+}
@@ -0,0 +1,260 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Files of a new day crate, relative to its directory.
+const TEMPLATES: &[(&str, &str)] = &[
+    ("Cargo.toml", include_str!("../templates/Cargo.toml.in")),
+    ("src/lib.rs", include_str!("../templates/lib.rs.in")),
+    ("src/main.rs", include_str!("../templates/main.rs.in")),
+];
+
+/// Fill in a template's `{{name}}`, `{{year}}` and `{{day}}`.
+fn render(template: &str, year: u32, day: u32) -> String {
+    template
+        .replace("{{name}}", &format!("aoc{}d{}", year, day))
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day}}", &day.to_string())
+}
+
+/// Sort key putting day crates in year and day order, after the rest.
+fn key(member: &str) -> (u32, u32, &str) {
+    member
+        .strip_prefix("aoc")
+        .and_then(|rest| rest.split_once('d'))
+        .and_then(|(year, day)| Some((year.parse().ok()?, day.parse().ok()?, member)))
+        .unwrap_or((0, 0, member))
+}
+
+/// Add `name` to the workspace `members` in `manifest`, keeping the list
+/// sorted. `None` if there is no `members` list to add to.
+fn add_member(manifest: &str, name: &str) -> Option<String> {
+    let start = manifest.find("members = [")? + "members = [".len();
+    let end = start + manifest[start..].find(']')?;
+    let mut members = manifest[start..end]
+        .split(',')
+        .map(|member| member.trim().trim_matches('"'))
+        .filter(|member| !member.is_empty())
+        .collect::<Vec<_>>();
+
+    members.push(name);
+    members.sort_by_key(|member| key(member));
+    members.dedup();
+
+    let list = members
+        .iter()
+        .map(|member| format!("\t\"{}\",\n", member))
+        .collect::<String>();
+
+    Some(format!(
+        "{}\n{}{}",
+        &manifest[..start],
+        list,
+        &manifest[end..]
+    ))
+}
+
+/// The crate a `[dependencies]` line is about.
+fn crate_of(line: &str) -> &str {
+    line.split(['=', ' ']).next().unwrap_or_default()
+}
+
+/// Add `name` to the `[dependencies]` in `manifest`, among the other day
+/// crates in year and day order. `None` if there are no dependencies.
+fn add_dependency(manifest: &str, name: &str) -> Option<String> {
+    let mut lines = manifest.lines().collect::<Vec<_>>();
+    let start = lines.iter().position(|line| *line == "[dependencies]")? + 1;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line.starts_with('['))
+            .unwrap_or(lines.len() - start);
+
+    if lines[start..end].iter().any(|line| crate_of(line) == name) {
+        return Some(manifest.to_owned());
+    }
+
+    let days = (start..end)
+        .filter(|&i| key(crate_of(lines[i])).0 != 0)
+        .collect::<Vec<_>>();
+    let at = days
+        .iter()
+        .copied()
+        .find(|&i| key(crate_of(lines[i])) > key(name))
+        .or_else(|| days.last().map(|&i| i + 1))
+        .unwrap_or_else(|| {
+            (start..end)
+                .rev()
+                .find(|&i| !lines[i].trim().is_empty())
+                .map_or(start, |i| i + 1)
+        });
+
+    let dependency = format!("{} = {{ path = \"../{}\" }}", name, name);
+    lines.insert(at, &dependency);
+
+    Some(lines.join("\n") + "\n")
+}
+
+/// Add `Day{day}` of the `name` crate to the `DAYS` in `registry`,
+/// keeping them in year and day order. `None` if there is no such list.
+fn add_day(registry: &str, name: &str, day: u32) -> Option<String> {
+    const LIST: &str = "pub static DAYS: &[&dyn Runner] = &[\n";
+
+    let start = registry.find(LIST)? + LIST.len();
+    let end = start + registry[start..].find("];")?;
+    let entry = format!("&{}::Day{},", name, day);
+    let mut days = registry[start..end]
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>();
+
+    days.push(&entry);
+    days.sort_by_key(|day| {
+        key(day
+            .trim_start_matches('&')
+            .split("::")
+            .next()
+            .unwrap_or(day))
+    });
+    days.dedup();
+
+    let list = days
+        .iter()
+        .map(|day| format!("    {}\n", day))
+        .collect::<String>();
+
+    Some(format!(
+        "{}{}{}",
+        &registry[..start],
+        list,
+        &registry[end..]
+    ))
+}
+
+/// The workspace `dir` is in: the closest directory at or above it whose
+/// `Cargo.toml` has a `[workspace]`.
+pub fn workspace(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .find(|dir| {
+            fs::read_to_string(dir.join("Cargo.toml"))
+                .is_ok_and(|manifest| manifest.lines().any(|l| l.trim() == "[workspace]"))
+        })
+        .map(Path::to_owned)
+}
+
+/// Create the crate for `year`'s `day` in `workspace`, make it a member
+/// and register it with `aoc`, returning its directory. An existing day
+/// is left alone.
+pub fn new(workspace: &Path, year: u32, day: u32) -> io::Result<PathBuf> {
+    let name = format!("aoc{}d{}", year, day);
+    let dir = workspace.join(&name);
+
+    if dir.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", dir.display()),
+        ));
+    }
+
+    let invalid = |path: &Path, what: &str| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{} has no {}", path.display(), what),
+        )
+    };
+
+    // Work everything out before writing anything.
+    let path = workspace.join("Cargo.toml");
+    let manifest = add_member(&fs::read_to_string(&path)?, &name)
+        .ok_or_else(|| invalid(&path, "workspace members list"))?;
+
+    let aoc_path = workspace.join("aoc").join("Cargo.toml");
+    let aoc_manifest = add_dependency(&fs::read_to_string(&aoc_path)?, &name)
+        .ok_or_else(|| invalid(&aoc_path, "[dependencies]"))?;
+
+    let registry_path = workspace.join("aoc").join("src").join("registry.rs");
+    let registry = add_day(&fs::read_to_string(&registry_path)?, &name, day)
+        .ok_or_else(|| invalid(&registry_path, "list of DAYS"))?;
+
+    for (file, template) in TEMPLATES {
+        let file = dir.join(file);
+        fs::create_dir_all(file.parent().unwrap_or(&dir))?;
+        fs::write(file, render(template, year, day))?;
+    }
+
+    fs::write(path, manifest)?;
+    fs::write(aoc_path, aoc_manifest)?;
+    fs::write(registry_path, registry)?;
+
+    Ok(dir)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn members_stay_sorted() {
+        let manifest = "[workspace]\nmembers = [\n\t\"aoc\",\n\t\"aoc2022d8\",\n]\n";
+
+        assert_eq!(
+            add_member(manifest, "aoc2022d10").unwrap(),
+            "[workspace]\nmembers = [\n\t\"aoc\",\n\t\"aoc2022d8\",\n\t\"aoc2022d10\",\n]\n"
+        );
+        assert!(add_member("[package]\n", "aoc2022d9").is_none());
+    }
+
+    #[test]
+    fn templates_are_filled_in() {
+        for (_, template) in TEMPLATES {
+            let rendered = render(template, 2023, 1);
+
+            assert!(!rendered.contains("{{"), "{}", rendered);
+        }
+
+        assert!(render(TEMPLATES[2].1, 2023, 1).contains("use aoc2023d1::Day1;"));
+    }
+
+    #[test]
+    fn dependencies_stay_sorted() {
+        let manifest = "[package]\nname = \"aoc\"\n\n[dependencies]\naoc-core = { path = \"../aoc-core\" }\naoc2022d1 = { path = \"../aoc2022d1\" }\naoc2022d10 = { path = \"../aoc2022d10\" }\nclap = \"4\"\n";
+        let added = add_dependency(manifest, "aoc2022d9").unwrap();
+
+        assert_eq!(
+            added,
+            "[package]\nname = \"aoc\"\n\n[dependencies]\naoc-core = { path = \"../aoc-core\" }\naoc2022d1 = { path = \"../aoc2022d1\" }\naoc2022d9 = { path = \"../aoc2022d9\" }\naoc2022d10 = { path = \"../aoc2022d10\" }\nclap = \"4\"\n"
+        );
+        assert_eq!(add_dependency(&added, "aoc2022d9").unwrap(), added);
+        assert!(add_dependency("[package]\n", "aoc2022d9").is_none());
+    }
+
+    #[test]
+    fn days_are_registered_by_year() {
+        let registry = "pub static DAYS: &[&dyn Runner] = &[\n    &aoc2022d1::Day1,\n    &aoc2022d2::Day2,\n];\n";
+
+        assert_eq!(
+            add_day(registry, "aoc2023d1", 1).unwrap(),
+            "pub static DAYS: &[&dyn Runner] = &[\n    &aoc2022d1::Day1,\n    &aoc2022d2::Day2,\n    &aoc2023d1::Day1,\n];\n"
+        );
+        assert!(add_day("pub fn find() {}\n", "aoc2023d1", 1).is_none());
+    }
+
+    #[test]
+    fn the_real_workspace_takes_a_new_day() {
+        let root = workspace(Path::new(env!("CARGO_MANIFEST_DIR"))).unwrap();
+        let read = |path: &[&str]| {
+            fs::read_to_string(path.iter().fold(root.clone(), |dir, p| dir.join(p))).unwrap()
+        };
+
+        assert!(add_member(&read(&["Cargo.toml"]), "aoc2023d1").is_some());
+        assert!(add_dependency(&read(&["aoc", "Cargo.toml"]), "aoc2023d1")
+            .unwrap()
+            .contains("aoc2023d1 = { path = \"../aoc2023d1\" }\n"));
+        assert!(
+            add_day(&read(&["aoc", "src", "registry.rs"]), "aoc2023d1", 1)
+                .unwrap()
+                .contains("    &aoc2023d1::Day1,\n];")
+        );
+    }
+}
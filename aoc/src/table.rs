@@ -0,0 +1,49 @@
+/// Render `rows` as a plain text table with left aligned columns.
+pub fn render(header: &[&str], rows: &[Vec<String>]) -> String {
+    let mut widths = header.iter().map(|h| h.len()).collect::<Vec<_>>();
+
+    for row in rows {
+        for (w, cell) in widths.iter_mut().zip(row) {
+            *w = (*w).max(cell.len());
+        }
+    }
+
+    let rule = widths.iter().map(|w| "-".repeat(*w)).collect::<Vec<_>>();
+
+    let mut out = vec![line(header, &widths), line(&rule, &widths)];
+    out.extend(rows.iter().map(|row| line(row, &widths)));
+
+    out.join("\n")
+}
+
+fn line<S: AsRef<str>>(cells: &[S], widths: &[usize]) -> String {
+    cells
+        .iter()
+        .zip(widths)
+        .map(|(cell, w)| format!("{:<w$}", cell.as_ref(), w = w))
+        .collect::<Vec<_>>()
+        .join("  ")
+        .trim_end()
+        .to_owned()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn render_works() {
+        let rows = vec![
+            vec!["1".to_owned(), "75622".to_owned()],
+            vec!["5".to_owned(), "CFFHVVHNC".to_owned()],
+        ];
+
+        assert_eq!(
+            render(&["Day", "Part 1"], &rows),
+            "Day  Part 1
+---  ---------
+1    75622
+5    CFFHVVHNC"
+        );
+    }
+}
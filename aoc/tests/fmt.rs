@@ -0,0 +1,27 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Exit code of `aoc fmt <day> - --check` on `input`.
+fn check(day: &str, input: &str) -> Option<i32> {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["fmt", day, "-", "--check"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    child.wait().unwrap().code()
+}
+
+#[test]
+fn check_flags_blank_line_runs_between_elves() {
+    assert_eq!(check("1", "1000\n2000\n\n3000\n"), Some(0));
+    assert_eq!(check("1", "1000\n2000\n\n\n\n3000\n"), Some(1));
+}
@@ -0,0 +1,12 @@
+use aoc2022d1::Day1;
+use aoc_core::bench::criterion::{criterion_group, criterion_main, Criterion};
+
+fn day1(c: &mut Criterion) {
+    aoc_core::bench::solution(c, &Day1);
+
+    #[cfg(feature = "bytes")]
+    aoc_core::bench::bytes(c, &Day1, aoc2022d1::bytes::parse);
+}
+
+criterion_group!(benches, day1);
+criterion_main!(benches);
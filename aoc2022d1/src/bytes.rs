@@ -0,0 +1,28 @@
+use crate::{Calorie, Input};
+use aoc_core::AocError;
+use nom::{
+    character::complete::{self, newline},
+    combinator::map_opt,
+    multi::{many1, separated_list1},
+    IResult,
+};
+
+fn calorie(input: &[u8]) -> IResult<&[u8], Calorie> {
+    map_opt(separated_list1(newline, complete::u32), crate::total)(input)
+}
+
+fn calories(input: &[u8]) -> IResult<&[u8], Input> {
+    separated_list1(many1(newline), calorie)(input)
+}
+
+/// Byte level [`crate::parse`].
+pub fn parse(input: &[u8]) -> Result<Input, AocError> {
+    aoc_core::bytes::finish(input, calories)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    aoc_core::bytes_agree!(crate::parse; "1000\n2000x\n", "4294967295\n1\n");
+}
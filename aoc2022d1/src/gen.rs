@@ -0,0 +1,30 @@
+use aoc_core::gen::Rng;
+
+/// `size` elves, each carrying one to six snacks.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            (0..rng.range(1..=6))
+                .map(|_| format!("{}\n", rng.range(1000..=60000)))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{parse, part1, part2};
+
+    #[test]
+    fn generated_inputs_solve() {
+        for seed in 0..20 {
+            let input = input(&mut Rng::new(seed), 50);
+            let calories = parse(&input).unwrap();
+
+            assert_eq!(calories.len(), 50);
+            assert!(u64::from(part1(&calories).unwrap()) <= part2(&calories));
+        }
+    }
+}
@@ -1,106 +1,275 @@
-use clap::Parser;
+#[cfg(feature = "bytes")]
+pub mod bytes;
+pub mod gen;
+pub mod lint;
+pub mod oracle;
+pub mod print;
+pub mod stream;
+
+use aoc_core::{
+    gen::Rng,
+    input::Source,
+    lint::Lint,
+    recover::{self, Report},
+    shrink::Split,
+    Answer, AocError, Diagnostic, Location, ParseResult, Part, Solution, Span,
+};
 use nom::{
+    branch::alt,
     character::complete::{self, newline},
+    combinator::{eof, map, map_opt, value},
+    error::context,
     multi::{many1, separated_list1},
-    IResult,
 };
 use std::cmp::Reverse;
+use std::io::BufRead;
 
-type Calorie = u32;
-
-#[derive(Parser, Debug)]
-#[command(
-    name = "aoc2022d1",
-    author = "Felipe Balbi <felipe@balbi.sh>",
-    about = "Advent of Code 2022 Day 1",
-    version = "0.1.0"
-)]
-pub struct Cli {
-    #[arg(short = '1', long = "part1", help = "Run part 1.")]
-    part1: bool,
-
-    #[arg(short = '2', long = "part2", help = "Run part 2.")]
-    part2: bool,
-}
+pub type Calorie = u32;
 
-impl Cli {
-    pub fn run(&self, input: &str) {
-        if self.part1 {
-            println!("Part 1: {}", part1(input));
-        }
+/// The parsed puzzle input.
+pub type Input = Vec<Calorie>;
 
-        if self.part2 {
-            println!("Part 2: {}", part2(input));
-        }
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input<'a> = Input;
+
+    fn name(&self) -> &'static str {
+        "aoc2022d1"
+    }
+
+    fn year(&self) -> u32 {
+        2022
+    }
+
+    fn day(&self) -> u32 {
+        1
+    }
+
+    fn manifest_dir(&self) -> &'static str {
+        env!("CARGO_MANIFEST_DIR")
+    }
+
+    fn parse_span<'a>(&self, input: Span<'a>) -> ParseResult<'a, Self::Input<'a>> {
+        parse_calories(input)
+    }
+
+    fn parse_recovering<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, AocError> {
+        let (lines, mut report) = recover::lines(Span::new(input), parse_line);
+        let calories = group_calories(lines, &mut report);
+        report.finish(calories)
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input).into())
+    }
+
+    fn stream(
+        &self,
+        source: &Source,
+        reader: &mut dyn BufRead,
+        parts: &[Part],
+    ) -> Option<Result<Vec<(Part, Answer)>, AocError>> {
+        Some(stream::solve(source, reader, parts))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        Some(gen::input(rng, size))
+    }
+
+    fn splits(&self, input: &str) -> Vec<Split> {
+        vec![Split::separated(input, "\n\n"), Split::lines(input)]
+    }
+
+    /// Snacks are summed while parsing, so [`print::input`] cannot
+    /// reproduce them. The canonical form keeps every snack and only
+    /// collapses runs of blank lines.
+    fn format(&self, input: &str) -> Result<String, AocError> {
+        self.parse_recovering(input)?;
+
+        Ok(print::canonical(input))
     }
+
+    fn lint(&self, input: &str) -> Vec<Lint> {
+        lint::lints(input)
+    }
+}
+
+/// Parse the calorie lists into one total per elf.
+pub fn parse(input: &str) -> Result<Input, AocError> {
+    Day1.parse(input)
 }
 
-fn parse_calorie(input: &str) -> IResult<&str, Calorie> {
-    let (input, calories) = separated_list1(newline, complete::u32)(input)?;
-    let calorie = calories.iter().sum();
+/// `None` when the total does not fit in a [`Calorie`].
+fn total(calories: impl IntoIterator<Item = Calorie>) -> Option<Calorie> {
+    calories.into_iter().try_fold(0, Calorie::checked_add)
+}
 
-    Ok((input, calorie))
+fn parse_calorie(input: Span<'_>) -> ParseResult<'_, Calorie> {
+    context(
+        "calorie counts adding up to at most 4294967295",
+        map_opt(
+            separated_list1(newline, context("a calorie count", complete::u32)),
+            total,
+        ),
+    )(input)
 }
 
-fn parse_calories(input: &str) -> IResult<&str, Vec<Calorie>> {
+fn parse_calories(input: Span<'_>) -> ParseResult<'_, Vec<Calorie>> {
     separated_list1(many1(newline), parse_calorie)(input)
 }
 
-fn part1(input: &str) -> u32 {
-    let (_, result) = parse_calories(input).unwrap();
+/// A single line on its own: a calorie count, or `None` for the blank
+/// line between two elves.
+fn parse_line(input: Span<'_>) -> ParseResult<'_, Option<Calorie>> {
+    alt((
+        value(None, eof),
+        map(context("a calorie count", complete::u32), Some),
+    ))(input)
+}
+
+/// Sum each elf's lines. Elves whose total overflows are reported
+/// at their first line rather than dropped silently.
+fn group_calories(lines: Vec<(Span<'_>, Option<Calorie>)>, report: &mut Report) -> Vec<Calorie> {
+    let mut groups = vec![];
+    let mut current: Option<(Span<'_>, Option<Calorie>)> = None;
+
+    let mut close =
+        |current: Option<(Span<'_>, Option<Calorie>)>, report: &mut Report| match current {
+            Some((_, Some(total))) => groups.push(total),
+            Some((span, None)) => report.errors.push(Diagnostic {
+                message: "calorie counts add up to more than 4294967295".to_owned(),
+                location: Some(Location::of(&span)),
+            }),
+            None => {}
+        };
 
-    result.into_iter().max().unwrap()
+    for (span, line) in lines {
+        match line {
+            Some(calorie) => {
+                let (_, total) = current.get_or_insert((span, Some(0)));
+                *total = total.and_then(|total| total.checked_add(calorie));
+            }
+            None => close(current.take(), report),
+        }
+    }
+
+    close(current, report);
+    groups
 }
 
-fn part2(input: &str) -> u32 {
-    let (_, mut result) = parse_calories(input).unwrap();
+pub fn part1(calories: &[Calorie]) -> Result<u32, AocError> {
+    calories
+        .iter()
+        .copied()
+        .max()
+        .ok_or_else(|| AocError::Validation("no calorie counts".to_owned()))
+}
+
+/// Three totals may not fit in a [`Calorie`], so this one is wider.
+pub fn part2(calories: &[Calorie]) -> u64 {
+    let mut result = calories.to_vec();
 
     result.sort_by_key(|k| Reverse(*k));
 
-    result.into_iter().take(3).sum()
+    result.into_iter().take(3).map(u64::from).sum()
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use aoc_core::shrink::{shrink, Predicate};
+
+    aoc_core::verify_answers!(Day1);
+    aoc_core::verify_examples!(Day1);
 
     #[test]
-    fn part1_works() {
+    fn garbage_halfway_fails() {
         let input = "1000
 2000
+
 3000
+x000
 
 4000
+";
 
-5000
-6000
+        match Day1.parse(input) {
+            Err(AocError::Parse(diagnostic)) => {
+                assert_eq!(
+                    diagnostic.to_string(),
+                    "unexpected input, parsing stopped here at 5:1"
+                )
+            }
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn totals_are_not_recomputed() {
+        let calories = vec![7];
 
-7000
-8000
-9000
+        assert_eq!(part1(&calories).unwrap(), 7);
+        assert_eq!(part2(&calories), 7);
+        assert_eq!(Day1.part2(&calories).unwrap(), 7u64);
+    }
 
-10000";
+    #[test]
+    fn overflowing_totals_are_errors() {
+        let input = "4294967295\n1\n";
 
-        assert_eq!(part1(input), 24000);
+        assert!(matches!(Day1.parse(input), Err(AocError::Parse(_))));
+        assert!(matches!(
+            Day1.parse_recovering(input),
+            Err(AocError::Malformed(_))
+        ));
+        assert!(matches!(part1(&[]), Err(AocError::Validation(_))));
     }
 
+    const EXAMPLE: &str = include_str!("../fixtures/example1/input.txt");
+
     #[test]
-    fn part2_works() {
-        let input = "1000
-2000
-3000
+    fn blocks_split_before_lines() {
+        let splits = Day1.splits("1\n2\n\n3\n");
 
-4000
+        assert_eq!(splits[0].pieces, ["1\n2", "3"]);
+        assert_eq!((splits[0].join)(&splits[0].pieces[1..]), "3\n");
+        assert_eq!(splits[1].pieces, ["1", "2", "", "3"]);
+    }
 
-5000
-6000
+    #[test]
+    fn shrinking_keeps_an_answer_the_oracle_disagrees_with() {
+        let answer = |input: &str| Answer::from(oracle::part1(&parse(input).unwrap()).unwrap());
+        let stale = answer("1000\n2000\n3000\n");
 
-7000
-8000
-9000
+        assert_eq!(
+            shrink(
+                &Day1,
+                Part::One,
+                EXAMPLE,
+                &Predicate::Differs(answer(EXAMPLE))
+            ),
+            None
+        );
+        assert_eq!(
+            shrink(&Day1, Part::One, EXAMPLE, &Predicate::Differs(stale)).as_deref(),
+            Some("10000\n")
+        );
+    }
 
-10000";
+    #[test]
+    fn shrinking_keeps_the_parse_error() {
+        let input = "1000\n2000\n\n3000\nx000\n\n4000\n";
 
-        assert_eq!(part2(input), 45000);
+        // `x000` on its own fails with a different message, so the line
+        // before it has to stay.
+        assert_eq!(
+            shrink(&Day1, Part::One, input, &Predicate::ParseError).as_deref(),
+            Some("3000\nx000\n")
+        );
     }
 }
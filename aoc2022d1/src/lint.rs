@@ -0,0 +1,59 @@
+use crate::parse_line;
+use aoc_core::lint::{Lint, Severity};
+use aoc_core::{recover, Span};
+
+pub fn lints(input: &str) -> Vec<Lint> {
+    let (lines, _) = recover::lines(Span::new(input), parse_line);
+    let mut lints = vec![];
+    let mut elves = 0;
+    let mut after_blank = true;
+
+    for (span, calorie) in &lines {
+        if calorie.is_some() && after_blank {
+            elves += 1;
+        }
+        after_blank = calorie.is_none();
+
+        if *calorie == Some(0) {
+            lints.push(Lint::at(
+                Severity::Info,
+                "zero-snack",
+                "a snack of 0 calories adds nothing to its elf".to_owned(),
+                span,
+            ));
+        }
+    }
+
+    if (1..3).contains(&elves) {
+        lints.push(Lint {
+            severity: Severity::Warning,
+            rule: "few-elves",
+            message: format!(
+                "part 2 adds up the top three elves, but there are only {}",
+                elves
+            ),
+            location: None,
+        });
+    }
+
+    lints
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn zero_snacks_and_few_elves_are_flagged() {
+        let input = "1000\n0\n\n\n2000\n";
+
+        assert_eq!(
+            lints(input)
+                .iter()
+                .map(|l| (l.rule, l.location.as_ref().map(|l| l.line)))
+                .collect::<Vec<_>>(),
+            vec![("zero-snack", Some(2)), ("few-elves", None)]
+        );
+        assert!(lints("1000\n\n2000\n\n3000\n").is_empty());
+    }
+}
@@ -0,0 +1,40 @@
+//! Deliberately naive reference solutions, for differential tests.
+
+use crate::Calorie;
+
+/// The elf carrying at least as much as every other elf.
+pub fn part1(calories: &[Calorie]) -> Option<Calorie> {
+    calories
+        .iter()
+        .copied()
+        .find(|&a| calories.iter().all(|&b| a >= b))
+}
+
+/// Sort every total and add up the last three.
+pub fn part2(calories: &[Calorie]) -> u64 {
+    let mut sorted = calories.to_vec();
+    sorted.sort();
+
+    sorted.iter().rev().take(3).map(|&c| u64::from(c)).sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Day1;
+    use aoc_core::{oracle::differential, Answer, Part};
+
+    #[test]
+    fn part1_agrees() {
+        differential(&Day1, Part::One, |input: &Vec<Calorie>| {
+            part1(input).map(Answer::from)
+        });
+    }
+
+    #[test]
+    fn part2_agrees() {
+        differential(&Day1, Part::Two, |input: &Vec<Calorie>| {
+            Some(part2(input).into())
+        });
+    }
+}
@@ -0,0 +1,45 @@
+use crate::Calorie;
+
+/// Print `calories` back in the puzzle format. Snacks were summed while
+/// parsing, so every elf carries a single one.
+pub fn input(calories: &[Calorie]) -> String {
+    calories
+        .iter()
+        .map(|calorie| format!("{}\n", calorie))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// `input` with exactly one blank line between elves and none before
+/// the first or after the last. Every snack stays on its own line.
+pub fn canonical(input: &str) -> String {
+    input
+        .lines()
+        .collect::<Vec<_>>()
+        .split(|line| line.is_empty())
+        .filter(|elf| !elf.is_empty())
+        .map(|elf| elf.join("\n") + "\n")
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parse;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn parse_reverses_print(calories in prop::collection::vec(any::<Calorie>(), 1..50)) {
+            let text = input(&calories);
+            prop_assert_eq!(parse(&text).unwrap(), calories);
+        }
+    }
+
+    #[test]
+    fn canonical_collapses_blank_lines() {
+        assert_eq!(canonical("1\n2\n\n\n\n3\n\n"), "1\n2\n\n3\n");
+        assert_eq!(canonical("1\n2\n\n3\n"), "1\n2\n\n3\n");
+    }
+}
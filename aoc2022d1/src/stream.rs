@@ -0,0 +1,78 @@
+use crate::Calorie;
+use aoc_core::{input::Source, stream::Stream, Answer, AocError, Part};
+use nom::{
+    character::streaming::{self, line_ending, multispace0},
+    combinator::map_opt,
+    multi::fold_many1,
+    sequence::{preceded, terminated},
+    IResult,
+};
+use std::cmp::Reverse;
+use std::io::BufRead;
+
+/// One elf's total, skipping the blank lines before it.
+fn elf(input: &[u8]) -> IResult<&[u8], Calorie> {
+    preceded(
+        multispace0,
+        map_opt(
+            fold_many1(
+                terminated(streaming::u32, line_ending),
+                || Some(0),
+                |total: Option<Calorie>, calorie| total?.checked_add(calorie),
+            ),
+            |total| total,
+        ),
+    )(input)
+}
+
+/// Every elf's total calories in `reader`, one elf at a time.
+pub fn elves<R: BufRead>(
+    source: &Source,
+    reader: R,
+) -> impl Iterator<Item = Result<Calorie, AocError>> {
+    Stream::new(source, reader, elf)
+}
+
+/// Solve `parts` in one pass, remembering only the top three elves.
+pub fn solve<R: BufRead>(
+    source: &Source,
+    reader: R,
+    parts: &[Part],
+) -> Result<Vec<(Part, Answer)>, AocError> {
+    let mut top = vec![];
+
+    for calories in elves(source, reader) {
+        top.push(calories?);
+        top.sort_by_key(|k| Reverse(*k));
+        top.truncate(3);
+    }
+
+    if top.is_empty() {
+        return Err(AocError::Validation("no calorie counts".to_owned()));
+    }
+
+    Ok(parts
+        .iter()
+        .map(|&part| match part {
+            Part::One => (part, top[0].into()),
+            Part::Two => (part, top.iter().copied().map(u64::from).sum::<u64>().into()),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::BufReader;
+
+    #[test]
+    fn solve_works() {
+        let input = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n\n7000\n8000\n9000\n\n10000";
+        let reader = BufReader::with_capacity(4, input.as_bytes());
+
+        assert_eq!(
+            solve(&Source::Stdin, reader, &[Part::One, Part::Two]).unwrap(),
+            vec![(Part::One, 24000u32.into()), (Part::Two, 45000u64.into())]
+        );
+    }
+}
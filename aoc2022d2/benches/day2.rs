@@ -0,0 +1,12 @@
+use aoc2022d2::Day2;
+use aoc_core::bench::criterion::{criterion_group, criterion_main, Criterion};
+
+fn day2(c: &mut Criterion) {
+    aoc_core::bench::solution(c, &Day2);
+
+    #[cfg(feature = "bytes")]
+    aoc_core::bench::bytes(c, &Day2, aoc2022d2::bytes::parse);
+}
+
+criterion_group!(benches, day2);
+criterion_main!(benches);
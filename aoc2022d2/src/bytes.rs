@@ -0,0 +1,34 @@
+use crate::{Column, Input, Shape};
+use aoc_core::AocError;
+use nom::{
+    character::complete::{char, newline},
+    combinator::map_res,
+    multi::separated_list1,
+    number::complete::u8,
+    sequence::separated_pair,
+    IResult,
+};
+
+fn shape(input: &[u8]) -> IResult<&[u8], Shape> {
+    map_res(u8, |b| Shape::try_from(b as char))(input)
+}
+
+fn column(input: &[u8]) -> IResult<&[u8], Column> {
+    map_res(u8, |b| Column::try_from(b as char))(input)
+}
+
+fn rounds(input: &[u8]) -> IResult<&[u8], Input> {
+    separated_list1(newline, separated_pair(shape, char(' '), column))(input)
+}
+
+/// Byte level [`crate::parse`].
+pub fn parse(input: &[u8]) -> Result<Input, AocError> {
+    aoc_core::bytes::finish(input, rounds)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    aoc_core::bytes_agree!(crate::parse; "A Y\nB W\n", "AY\n", "A B\n");
+}
@@ -0,0 +1,32 @@
+use aoc_core::gen::Rng;
+
+/// `size` rounds of rock paper scissors.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            format!(
+                "{} {}\n",
+                rng.pick(&['A', 'B', 'C']),
+                rng.pick(&['X', 'Y', 'Z'])
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{parse, part1, part2};
+
+    #[test]
+    fn generated_inputs_solve() {
+        for seed in 0..20 {
+            let input = input(&mut Rng::new(seed), 50);
+            let rounds = parse(&input).unwrap();
+
+            assert_eq!(rounds.len(), 50);
+            assert!((50..=450).contains(&part1(&rounds)));
+            assert!((50..=450).contains(&part2(&rounds)));
+        }
+    }
+}
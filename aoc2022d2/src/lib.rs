@@ -1,53 +1,104 @@
-use clap::Parser;
+#[cfg(feature = "bytes")]
+pub mod bytes;
+pub mod gen;
+pub mod oracle;
+pub mod print;
+pub mod stream;
+
+use aoc_core::{
+    gen::Rng, input::Source, recover, Answer, AocError, ParseResult, Part, Solution, Span,
+};
 use nom::{
     character::complete::{self, newline},
-    combinator::map,
+    combinator::map_res,
+    error::context,
     multi::separated_list1,
     sequence::separated_pair,
-    IResult,
 };
+use serde::Serialize;
+use std::io::BufRead;
 
-#[derive(Parser, Debug)]
-#[command(
-    name = "aoc2022d2",
-    author = "Felipe Balbi <felipe@balbi.sh>",
-    about = "Advent of Code 2022 Day 2",
-    version = "0.1.0"
-)]
-pub struct Cli {
-    #[arg(short = '1', long = "part1", help = "Run part 1.")]
-    part1: bool,
-
-    #[arg(short = '2', long = "part2", help = "Run part 2.")]
-    part2: bool,
-}
-
-impl Cli {
-    pub fn run(&self, input: &str) {
-        if self.part1 {
-            println!("Part 1: {}", part1(input));
-        }
+/// The parsed puzzle input.
+pub type Input = Vec<(Shape, Column)>;
 
-        if self.part2 {
-            println!("Part 2: {}", part2(input));
-        }
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input<'a> = Input;
+
+    fn name(&self) -> &'static str {
+        "aoc2022d2"
+    }
+
+    fn year(&self) -> u32 {
+        2022
+    }
+
+    fn day(&self) -> u32 {
+        2
+    }
+
+    fn manifest_dir(&self) -> &'static str {
+        env!("CARGO_MANIFEST_DIR")
+    }
+
+    fn parse_span<'a>(&self, input: Span<'a>) -> ParseResult<'a, Self::Input<'a>> {
+        parse_input(input)
+    }
+
+    fn parse_recovering<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, AocError> {
+        let (games, report) = recover::lines(Span::new(input), parse_line);
+        report.finish(games.into_iter().map(|(_, game)| game).collect())
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input).into())
+    }
+
+    fn stream(
+        &self,
+        source: &Source,
+        reader: &mut dyn BufRead,
+        parts: &[Part],
+    ) -> Option<Result<Vec<(Part, Answer)>, AocError>> {
+        Some(stream::solve(source, reader, parts))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        Some(gen::input(rng, size))
+    }
+
+    fn print(&self, input: &Self::Input<'_>) -> Option<String> {
+        Some(print::input(input))
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
-enum Shape {
+/// Parse the strategy guide into one `(opponent, second column)` pair
+/// per round.
+pub fn parse(input: &str) -> Result<Input, AocError> {
+    Day2.parse(input)
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum Shape {
     Rock,
     Paper,
     Scissors,
 }
 
-impl From<char> for Shape {
-    fn from(item: char) -> Self {
+impl TryFrom<char> for Shape {
+    type Error = String;
+
+    fn try_from(item: char) -> Result<Self, Self::Error> {
         match item {
-            'A' | 'X' => Self::Rock,
-            'B' | 'Y' => Self::Paper,
-            'C' | 'Z' => Self::Scissors,
-            _ => unreachable!(),
+            'A' => Ok(Self::Rock),
+            'B' => Ok(Self::Paper),
+            'C' => Ok(Self::Scissors),
+            _ => Err(format!("`{}` is not a shape", item)),
         }
     }
 }
@@ -62,20 +113,51 @@ impl From<Shape> for u32 {
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
-enum Strategy {
+/// The second column of the strategy guide. Part 1 reads it as the
+/// [`Shape`] to play, part 2 as the [`Strategy`] to follow.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum Column {
+    X,
+    Y,
+    Z,
+}
+
+impl TryFrom<char> for Column {
+    type Error = String;
+
+    fn try_from(item: char) -> Result<Self, Self::Error> {
+        match item {
+            'X' => Ok(Self::X),
+            'Y' => Ok(Self::Y),
+            'Z' => Ok(Self::Z),
+            _ => Err(format!("`{}` is not X, Y or Z", item)),
+        }
+    }
+}
+
+impl From<&Column> for Shape {
+    fn from(item: &Column) -> Self {
+        match item {
+            Column::X => Self::Rock,
+            Column::Y => Self::Paper,
+            Column::Z => Self::Scissors,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum Strategy {
     Win,
     Lose,
     Draw,
 }
 
-impl From<char> for Strategy {
-    fn from(item: char) -> Self {
+impl From<&Column> for Strategy {
+    fn from(item: &Column) -> Self {
         match item {
-            'X' => Self::Lose,
-            'Y' => Self::Draw,
-            'Z' => Self::Win,
-            _ => unreachable!(),
+            Column::X => Self::Lose,
+            Column::Y => Self::Draw,
+            Column::Z => Self::Win,
         }
     }
 }
@@ -90,121 +172,111 @@ impl From<Strategy> for u32 {
     }
 }
 
-fn parse_shape(input: &str) -> IResult<&str, Shape> {
-    map(complete::one_of("ABCXYZ"), |c| c.into())(input)
+fn parse_shape(input: Span<'_>) -> ParseResult<'_, Shape> {
+    map_res(complete::one_of("ABC"), Shape::try_from)(input)
 }
 
-fn parse_strategy(input: &str) -> IResult<&str, Strategy> {
-    map(complete::one_of("XYZ"), |c| c.into())(input)
+fn parse_column(input: Span<'_>) -> ParseResult<'_, Column> {
+    map_res(complete::one_of("XYZ"), Column::try_from)(input)
 }
 
-fn parse_line_part1(input: &str) -> IResult<&str, (Shape, Shape)> {
-    separated_pair(parse_shape, complete::char(' '), parse_shape)(input)
+fn parse_line(input: Span<'_>) -> ParseResult<'_, (Shape, Column)> {
+    context(
+        "`<A|B|C> <X|Y|Z>`",
+        separated_pair(parse_shape, complete::char(' '), parse_column),
+    )(input)
 }
 
-fn parse_line_part2(input: &str) -> IResult<&str, (Shape, Strategy)> {
-    separated_pair(parse_shape, complete::char(' '), parse_strategy)(input)
+fn parse_input(input: Span<'_>) -> ParseResult<'_, Vec<(Shape, Column)>> {
+    separated_list1(newline, parse_line)(input)
 }
 
-fn parse_input_part1(input: &str) -> IResult<&str, Vec<(Shape, Shape)>> {
-    separated_list1(newline, parse_line_part1)(input)
+/// Score of one round when the second column is the shape to play.
+fn score1((opponent, column): &(Shape, Column)) -> u32 {
+    let player = Shape::from(column);
+    let result = match opponent {
+        Shape::Rock => match player {
+            Shape::Paper => Strategy::Win,
+            Shape::Rock => Strategy::Draw,
+            Shape::Scissors => Strategy::Lose,
+        },
+        Shape::Paper => match player {
+            Shape::Paper => Strategy::Draw,
+            Shape::Rock => Strategy::Lose,
+            Shape::Scissors => Strategy::Win,
+        },
+        Shape::Scissors => match player {
+            Shape::Paper => Strategy::Lose,
+            Shape::Rock => Strategy::Win,
+            Shape::Scissors => Strategy::Draw,
+        },
+    };
+
+    let shape_value: u32 = player.into();
+    let result_value: u32 = result.into();
+
+    shape_value + result_value
 }
 
-fn parse_input_part2(input: &str) -> IResult<&str, Vec<(Shape, Strategy)>> {
-    separated_list1(newline, parse_line_part2)(input)
+/// Score of one round when the second column is the desired outcome.
+fn score2((shape, column): &(Shape, Column)) -> u32 {
+    let strategy = Strategy::from(column);
+
+    let play = match shape {
+        Shape::Rock => match strategy {
+            Strategy::Win => Shape::Paper,
+            Strategy::Draw => Shape::Rock,
+            Strategy::Lose => Shape::Scissors,
+        },
+        Shape::Paper => match strategy {
+            Strategy::Win => Shape::Scissors,
+            Strategy::Draw => Shape::Paper,
+            Strategy::Lose => Shape::Rock,
+        },
+        Shape::Scissors => match strategy {
+            Strategy::Win => Shape::Rock,
+            Strategy::Draw => Shape::Scissors,
+            Strategy::Lose => Shape::Paper,
+        },
+    };
+
+    let shape_value: u32 = play.into();
+    let strategy_value: u32 = strategy.into();
+
+    shape_value + strategy_value
 }
 
-fn part1(input: &str) -> u32 {
-    let (_, games) = parse_input_part1(input).unwrap();
-    let mut score: u32 = 0;
-
-    for game in games {
-        let opponent = game.0;
-        let player = game.1;
-
-        let result = match opponent {
-            Shape::Rock => match player {
-                Shape::Paper => Strategy::Win,
-                Shape::Rock => Strategy::Draw,
-                Shape::Scissors => Strategy::Lose,
-            },
-            Shape::Paper => match player {
-                Shape::Paper => Strategy::Draw,
-                Shape::Rock => Strategy::Lose,
-                Shape::Scissors => Strategy::Win,
-            },
-            Shape::Scissors => match player {
-                Shape::Paper => Strategy::Lose,
-                Shape::Rock => Strategy::Win,
-                Shape::Scissors => Strategy::Draw,
-            },
-        };
-
-        let shape_value: u32 = player.clone().into();
-        let result_value: u32 = result.clone().into();
-
-        score += shape_value;
-        score += result_value;
-    }
-
-    score
+pub fn part1(games: &[(Shape, Column)]) -> u64 {
+    games.iter().map(|game| u64::from(score1(game))).sum()
 }
 
-fn part2(input: &str) -> u32 {
-    let (_, games) = parse_input_part2(input).unwrap();
-    let mut score: u32 = 0;
-
-    for game in games {
-        let shape = game.0;
-        let strategy = game.1;
-
-        let play = match shape {
-            Shape::Rock => match strategy {
-                Strategy::Win => Shape::Paper,
-                Strategy::Draw => Shape::Rock,
-                Strategy::Lose => Shape::Scissors,
-            },
-            Shape::Paper => match strategy {
-                Strategy::Win => Shape::Scissors,
-                Strategy::Draw => Shape::Paper,
-                Strategy::Lose => Shape::Rock,
-            },
-            Shape::Scissors => match strategy {
-                Strategy::Win => Shape::Rock,
-                Strategy::Draw => Shape::Scissors,
-                Strategy::Lose => Shape::Paper,
-            },
-        };
-
-        let shape_value: u32 = play.clone().into();
-        let strategy_value: u32 = strategy.clone().into();
-
-        score += shape_value;
-        score += strategy_value;
-    }
-
-    score
+pub fn part2(games: &[(Shape, Column)]) -> u64 {
+    games.iter().map(|game| u64::from(score2(game))).sum()
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
 
-    #[test]
-    fn part1_works() {
-        let input = "A Y
-B X
-C Z";
+    aoc_core::verify_answers!(Day2);
+    aoc_core::verify_examples!(Day2);
 
-        assert_eq!(part1(input), 15);
+    #[test]
+    fn every_round_is_scored() {
+        let shapes = [Shape::Rock, Shape::Paper, Shape::Scissors];
+        let columns = [Column::X, Column::Y, Column::Z];
+        let games = shapes
+            .iter()
+            .flat_map(|a| columns.iter().map(move |b| (a.clone(), b.clone())))
+            .collect::<Vec<_>>();
+
+        assert_eq!(part1(&games), 45);
+        assert_eq!(part2(&games), 45);
     }
 
     #[test]
-    fn part2_works() {
-        let input = "A Y
-B X
-C Z";
-
-        assert_eq!(part2(input), 12);
+    fn second_column_is_x_y_or_z() {
+        assert_eq!(parse("A Y\n").unwrap(), vec![(Shape::Rock, Column::Y)]);
+        assert!(matches!(parse("A B\n"), Err(AocError::Parse(_))));
     }
 }
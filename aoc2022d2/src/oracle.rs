@@ -0,0 +1,57 @@
+//! Deliberately naive reference solutions, for differential tests.
+
+use crate::{Column, Shape};
+
+fn row(shape: &Shape) -> usize {
+    match shape {
+        Shape::Rock => 0,
+        Shape::Paper => 1,
+        Shape::Scissors => 2,
+    }
+}
+
+fn col(column: &Column) -> usize {
+    match column {
+        Column::X => 0,
+        Column::Y => 1,
+        Column::Z => 2,
+    }
+}
+
+/// Every round's score read off the puzzle text, by A/B/C then X/Y/Z,
+/// with X, Y and Z as shapes.
+const SCORES1: [[u32; 3]; 3] = [[4, 8, 3], [1, 5, 9], [7, 2, 6]];
+
+/// The same with X, Y and Z as lose, draw and win.
+const SCORES2: [[u32; 3]; 3] = [[3, 4, 8], [1, 5, 9], [2, 6, 7]];
+
+pub fn part1(games: &[(Shape, Column)]) -> u64 {
+    games
+        .iter()
+        .map(|(theirs, column)| u64::from(SCORES1[row(theirs)][col(column)]))
+        .sum()
+}
+
+pub fn part2(games: &[(Shape, Column)]) -> u64 {
+    games
+        .iter()
+        .map(|(theirs, column)| u64::from(SCORES2[row(theirs)][col(column)]))
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Day2, Input};
+    use aoc_core::{oracle::differential, Part};
+
+    #[test]
+    fn part1_agrees() {
+        differential(&Day2, Part::One, |input: &Input| Some(part1(input).into()));
+    }
+
+    #[test]
+    fn part2_agrees() {
+        differential(&Day2, Part::Two, |input: &Input| Some(part2(input).into()));
+    }
+}
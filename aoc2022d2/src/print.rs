@@ -0,0 +1,49 @@
+use crate::{Column, Shape};
+
+fn shape_letter(shape: &Shape) -> char {
+    match shape {
+        Shape::Rock => 'A',
+        Shape::Paper => 'B',
+        Shape::Scissors => 'C',
+    }
+}
+
+fn column_letter(column: &Column) -> char {
+    match column {
+        Column::X => 'X',
+        Column::Y => 'Y',
+        Column::Z => 'Z',
+    }
+}
+
+/// Print `games` back in the puzzle format, opponent first with A, B
+/// and C, then the second column with X, Y and Z.
+pub fn input(games: &[(Shape, Column)]) -> String {
+    games
+        .iter()
+        .map(|(theirs, column)| format!("{} {}\n", shape_letter(theirs), column_letter(column)))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parse;
+    use proptest::prelude::*;
+
+    fn shape() -> impl Strategy<Value = Shape> {
+        prop_oneof![Just(Shape::Rock), Just(Shape::Paper), Just(Shape::Scissors)]
+    }
+
+    fn column() -> impl Strategy<Value = Column> {
+        prop_oneof![Just(Column::X), Just(Column::Y), Just(Column::Z)]
+    }
+
+    proptest! {
+        #[test]
+        fn parse_reverses_print(games in prop::collection::vec((shape(), column()), 1..50)) {
+            let text = input(&games);
+            prop_assert_eq!(parse(&text).unwrap(), games);
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{score1, score2, Column, Shape};
+use aoc_core::{input::Source, stream::Stream, Answer, AocError, Part};
+use nom::{
+    character::streaming::{char, line_ending},
+    combinator::map_res,
+    number::streaming::u8,
+    sequence::{separated_pair, terminated},
+    IResult,
+};
+use std::io::BufRead;
+
+fn shape(input: &[u8]) -> IResult<&[u8], Shape> {
+    map_res(u8, |b| Shape::try_from(b as char))(input)
+}
+
+fn column(input: &[u8]) -> IResult<&[u8], Column> {
+    map_res(u8, |b| Column::try_from(b as char))(input)
+}
+
+fn round(input: &[u8]) -> IResult<&[u8], (Shape, Column)> {
+    terminated(separated_pair(shape, char(' '), column), line_ending)(input)
+}
+
+/// Every round in `reader`, one at a time.
+pub fn rounds<R: BufRead>(
+    source: &Source,
+    reader: R,
+) -> impl Iterator<Item = Result<(Shape, Column), AocError>> {
+    Stream::new(source, reader, round)
+}
+
+/// Solve `parts` in one pass, keeping a running score for each.
+pub fn solve<R: BufRead>(
+    source: &Source,
+    reader: R,
+    parts: &[Part],
+) -> Result<Vec<(Part, Answer)>, AocError> {
+    // Multi-gigabyte inputs have more rounds than a u32 score can count.
+    let (mut one, mut two) = (0u64, 0u64);
+
+    for round in rounds(source, reader) {
+        let round = round?;
+        one += u64::from(score1(&round));
+        two += u64::from(score2(&round));
+    }
+
+    Ok(parts
+        .iter()
+        .map(|&part| match part {
+            Part::One => (part, one.into()),
+            Part::Two => (part, two.into()),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::BufReader;
+
+    #[test]
+    fn solve_works() {
+        let reader = BufReader::with_capacity(3, "A Y\r\nB X\nC Z\n".as_bytes());
+
+        assert_eq!(
+            solve(&Source::Stdin, reader, &[Part::One, Part::Two]).unwrap(),
+            vec![(Part::One, 15u32.into()), (Part::Two, 12u32.into())]
+        );
+    }
+}
@@ -0,0 +1,12 @@
+use aoc2022d3::Day3;
+use aoc_core::bench::criterion::{criterion_group, criterion_main, Criterion};
+
+fn day3(c: &mut Criterion) {
+    aoc_core::bench::solution(c, &Day3);
+
+    #[cfg(feature = "bytes")]
+    aoc_core::bench::bytes(c, &Day3, aoc2022d3::bytes::parse);
+}
+
+criterion_group!(benches, day3);
+criterion_main!(benches);
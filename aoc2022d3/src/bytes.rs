@@ -0,0 +1,40 @@
+use crate::{char_to_priority, Input, Item, Rucksack};
+use aoc_core::AocError;
+use nom::{
+    bytes::complete::take_while1, character::complete::newline, combinator::map, multi::many1,
+    sequence::terminated, IResult,
+};
+
+fn item(b: &u8) -> Item {
+    let c = *b as char;
+    (char_to_priority(c), c)
+}
+
+fn rucksack(input: &[u8]) -> IResult<&[u8], Rucksack> {
+    map(
+        take_while1(|b: u8| b.is_ascii_alphabetic()),
+        |items: &[u8]| {
+            let (left, right) = items.split_at(items.len() / 2);
+            (
+                left.iter().map(item).collect(),
+                right.iter().map(item).collect(),
+            )
+        },
+    )(input)
+}
+
+fn rucksacks(input: &[u8]) -> IResult<&[u8], Input> {
+    many1(terminated(rucksack, newline))(input)
+}
+
+/// Byte level [`crate::parse`].
+pub fn parse(input: &[u8]) -> Result<Input, AocError> {
+    aoc_core::bytes::finish(input, rucksacks)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    aoc_core::bytes_agree!(crate::parse; "abc1\n", "ab cd\n");
+}
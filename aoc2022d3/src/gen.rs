@@ -0,0 +1,69 @@
+use aoc_core::gen::Rng;
+
+const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// One rucksack: `shared` in both compartments, `badge` in one of
+/// them, and otherwise letters from `left` or `right` only.
+fn rucksack(rng: &mut Rng, shared: u8, badge: u8, left: &[u8], right: &[u8]) -> String {
+    let len = rng.range(2..=left.len() + 1);
+    let mut halves = [vec![shared], vec![shared]];
+
+    if badge != shared {
+        halves[rng.range(0..=1)].push(badge);
+    }
+
+    for (half, pool) in halves.iter_mut().zip([left, right]) {
+        while half.len() < len {
+            half.push(*rng.pick(pool));
+        }
+        rng.shuffle(half);
+    }
+
+    String::from_utf8(halves.concat()).unwrap()
+}
+
+/// At least `size` rucksacks, in groups of three. Every rucksack has
+/// exactly one item in both compartments, and every group exactly one
+/// badge.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let groups = size.max(1).div_ceil(3);
+    let mut rucksacks = String::new();
+
+    for _ in 0..groups {
+        let mut items = ITEMS.to_vec();
+        rng.shuffle(&mut items);
+
+        let badge = items.pop().unwrap();
+
+        // Each rucksack draws from its own 17 letters, so the badge is
+        // the only item all three share.
+        for pool in items.chunks(17) {
+            let (shared, pool) = pool.split_first().unwrap();
+            let shared = if rng.one_in(4) { badge } else { *shared };
+            let (left, right) = pool.split_at(pool.len() / 2);
+
+            rucksacks.push_str(&rucksack(rng, shared, badge, left, right));
+            rucksacks.push('\n');
+        }
+    }
+
+    rucksacks
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{parse, part1, part2};
+
+    #[test]
+    fn generated_inputs_solve() {
+        for seed in 0..20 {
+            let input = input(&mut Rng::new(seed), 50);
+            let rucksacks = parse(&input).unwrap();
+
+            assert_eq!(rucksacks.len(), 51);
+            assert!(part1(&rucksacks) <= 51 * 52);
+            assert!(part2(&rucksacks).unwrap() <= 17 * 52);
+        }
+    }
+}
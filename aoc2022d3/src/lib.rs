@@ -1,43 +1,92 @@
-use clap::Parser;
+#[cfg(feature = "bytes")]
+pub mod bytes;
+pub mod gen;
+pub mod lint;
+pub mod oracle;
+pub mod print;
+
+use aoc_core::{
+    gen::Rng, lint::Lint, recover, shrink::Split, Answer, AocError, ParseResult, Solution, Span,
+};
 use nom::{
     character::complete::{self, newline},
     combinator::map,
+    error::context,
     multi::many1,
     sequence::terminated,
-    IResult,
 };
 
-type Priority = u32;
-type Type = char;
-type Item = (Priority, Type);
-type Compartment = Vec<Item>;
-type Rucksack = (Compartment, Compartment);
-
-#[derive(Parser, Debug)]
-#[command(
-    name = "aoc2022d3",
-    author = "Felipe Balbi <felipe@balbi.sh>",
-    about = "Advent of Code 2022 Day 3",
-    version = "0.1.0"
-)]
-pub struct Cli {
-    #[arg(short = '1', long = "part1", help = "Run part 1.")]
-    part1: bool,
-
-    #[arg(short = '2', long = "part2", help = "Run part 2.")]
-    part2: bool,
-}
+pub type Priority = u32;
+pub type Type = char;
+pub type Item = (Priority, Type);
+pub type Compartment = Vec<Item>;
+pub type Rucksack = (Compartment, Compartment);
+
+/// The parsed puzzle input.
+pub type Input = Vec<Rucksack>;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input<'a> = Input;
+
+    fn name(&self) -> &'static str {
+        "aoc2022d3"
+    }
+
+    fn year(&self) -> u32 {
+        2022
+    }
+
+    fn day(&self) -> u32 {
+        3
+    }
+
+    fn manifest_dir(&self) -> &'static str {
+        env!("CARGO_MANIFEST_DIR")
+    }
+
+    fn parse_span<'a>(&self, input: Span<'a>) -> ParseResult<'a, Self::Input<'a>> {
+        parse_input(input)
+    }
 
-impl Cli {
-    pub fn run(&self, input: &str) {
-        if self.part1 {
-            println!("Part 1: {}", part1(input));
-        }
+    fn parse_recovering<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, AocError> {
+        let (rucksacks, report) = recover::lines(
+            Span::new(input),
+            context("a rucksack of item letters", parse_rucksack),
+        );
+        report.finish(rucksacks.into_iter().map(|(_, r)| r).collect())
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        part2(input).map(Answer::from)
+    }
 
-        if self.part2 {
-            println!("Part 2: {}", part2(input));
-        }
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        Some(gen::input(rng, size))
     }
+
+    fn splits(&self, input: &str) -> Vec<Split> {
+        vec![Split::chunks(input, 3), Split::lines(input)]
+    }
+
+    fn print(&self, input: &Self::Input<'_>) -> Option<String> {
+        Some(print::input(input))
+    }
+
+    fn lint(&self, input: &str) -> Vec<Lint> {
+        lint::lints(input)
+    }
+}
+
+/// Parse every rucksack into its two compartments, each item paired
+/// with its priority.
+pub fn parse(input: &str) -> Result<Input, AocError> {
+    Day3.parse(input)
 }
 
 fn char_to_priority(c: char) -> u32 {
@@ -48,14 +97,14 @@ fn char_to_priority(c: char) -> u32 {
     }
 }
 
-fn parse_item(input: &str) -> IResult<&str, Item> {
+fn parse_item(input: Span<'_>) -> ParseResult<'_, Item> {
     map(
-        complete::one_of("abcdefghjklmnopqrstuvwxyzABCDEFGHJKLMNOPQRSTUVWXYZ"),
+        complete::one_of("abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ"),
         |c| (char_to_priority(c), c),
     )(input)
 }
 
-fn parse_rucksack(input: &str) -> IResult<&str, Rucksack> {
+fn parse_rucksack(input: Span<'_>) -> ParseResult<'_, Rucksack> {
     let (input, items) = many1(parse_item)(input)?;
     let mid = items.len() / 2;
     let (left, right) = items.split_at(mid);
@@ -65,31 +114,31 @@ fn parse_rucksack(input: &str) -> IResult<&str, Rucksack> {
     Ok((input, rucksack))
 }
 
-fn parse_input_part1(input: &str) -> IResult<&str, Vec<Rucksack>> {
-    many1(terminated(parse_rucksack, newline))(input)
-}
-
-fn parse_input_part2(input: &str) -> IResult<&str, Vec<Rucksack>> {
-    parse_input_part1(input)
+fn parse_input(input: Span<'_>) -> ParseResult<'_, Vec<Rucksack>> {
+    many1(terminated(
+        context("a rucksack of item letters", parse_rucksack),
+        newline,
+    ))(input)
 }
 
-fn part1(input: &str) -> u32 {
-    let (_, rucksacks) = parse_input_part1(input).unwrap();
-
+pub fn part1(rucksacks: &[Rucksack]) -> u64 {
     let mut priorities = 0;
 
-    for (mut left, mut right) in rucksacks {
+    for (left, right) in rucksacks {
+        let mut left = left.clone();
+        let mut right = right.clone();
+
         left.sort();
         left.dedup();
 
         right.sort();
         right.dedup();
 
-        let priority: Priority = left
+        let priority = left
             .into_iter()
             .filter(|item| right.contains(item))
-            .map(|(p, _)| p)
-            .fold(0, |acc, p| acc + p);
+            .map(|(p, _)| u64::from(p))
+            .sum::<u64>();
 
         priorities += priority;
     }
@@ -97,17 +146,19 @@ fn part1(input: &str) -> u32 {
     priorities
 }
 
-fn part2(input: &str) -> u32 {
-    let (_, rucksacks) = parse_input_part2(input).unwrap();
+pub fn part2(rucksacks: &[Rucksack]) -> Result<u64, AocError> {
+    if !rucksacks.len().is_multiple_of(3) {
+        return Err(AocError::Validation(format!(
+            "{} rucksacks cannot be split into groups of three",
+            rucksacks.len()
+        )));
+    }
 
     let mut badges = 0;
 
     let groups = rucksacks
-        .into_iter()
-        .map(|(mut left, mut right)| {
-            left.append(&mut right);
-            left
-        })
+        .iter()
+        .map(|(left, right)| [left.as_slice(), right.as_slice()].concat())
         .collect::<Vec<_>>();
 
     for chunk in groups.chunks(3) {
@@ -125,44 +176,44 @@ fn part2(input: &str) -> u32 {
         three.dedup();
 
         let badge = one
-            .into_iter()
+            .iter()
             .filter(|item| two.contains(item) && three.contains(item))
-            .map(|(p, _)| *p)
-            .fold(0, |acc, p| acc + p);
+            .map(|&(p, _)| u64::from(p))
+            .sum::<u64>();
 
         badges += badge;
     }
 
-    badges
+    Ok(badges)
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
 
+    aoc_core::verify_answers!(Day3);
+    aoc_core::verify_examples!(Day3);
+
     #[test]
-    fn part1_works() {
-        let input = "vJrwpWtwJgWrhcsFMMfFFhFp
-jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
-PmmdzqPrVvPwwTWBwg
-wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
-ttgJtRGJQctTZtZT
-CrZsJsPPZsGzwwsLwLmpwMDw
-";
+    fn priorities_come_from_the_input() {
+        let rucksack = (vec![(100, 'a'), (1, 'b')], vec![(100, 'a')]);
+        let rucksacks = vec![rucksack.clone(), rucksack.clone(), rucksack];
 
-        assert_eq!(part1(input), 157);
+        assert_eq!(part1(&rucksacks), 300);
+        assert_eq!(part2(&rucksacks).unwrap(), 101);
     }
 
     #[test]
-    fn part2_works() {
-        let input = "vJrwpWtwJgWrhcsFMMfFFhFp
-jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
-PmmdzqPrVvPwwTWBwg
-wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
-ttgJtRGJQctTZtZT
-CrZsJsPPZsGzwwsLwLmpwMDw
-";
-
-        assert_eq!(part2(input), 70);
+    fn every_letter_is_an_item() {
+        assert_eq!(part1(&parse("iXyi\nIaIb\n").unwrap()), 9 + 35);
+    }
+
+    #[test]
+    fn groups_split_before_lines() {
+        let splits = Day3.splits("a\nb\nc\nd\ne\nf\n");
+
+        assert_eq!(splits[0].pieces, ["a\nb\nc", "d\ne\nf"]);
+        assert_eq!((splits[0].join)(&splits[0].pieces[1..]), "d\ne\nf\n");
+        assert_eq!(splits[1].pieces.len(), 6);
     }
 }
@@ -0,0 +1,82 @@
+use crate::parse_item;
+use aoc_core::lint::{Lint, Severity};
+use aoc_core::{recover, Span};
+use nom::multi::many1;
+
+pub fn lints(input: &str) -> Vec<Lint> {
+    let (rucksacks, _) = recover::lines(Span::new(input), many1(parse_item));
+    let mut lints = vec![];
+
+    for (line, items) in &rucksacks {
+        if items.len() % 2 == 1 {
+            lints.push(Lint::at(
+                Severity::Warning,
+                "odd-rucksack",
+                format!(
+                    "{} items do not split evenly, the second compartment gets the odd one",
+                    items.len()
+                ),
+                line,
+            ));
+        }
+
+        let (left, right) = items.split_at(items.len() / 2);
+        let mut shared = left
+            .iter()
+            .filter(|item| right.contains(item))
+            .map(|&(_, t)| t)
+            .collect::<Vec<_>>();
+        shared.sort();
+        shared.dedup();
+
+        if shared.len() != 1 {
+            lints.push(Lint::at(
+                Severity::Warning,
+                "shared-items",
+                format!(
+                    "the compartments share {} item types instead of exactly one",
+                    shared.len()
+                ),
+                line,
+            ));
+        }
+    }
+
+    let leftover = rucksacks.len() % 3;
+    if leftover != 0 {
+        lints.push(Lint::at(
+            Severity::Error,
+            "incomplete-group",
+            format!(
+                "{} rucksacks cannot be split into groups of three, this group has {}",
+                rucksacks.len(),
+                leftover
+            ),
+            &rucksacks[rucksacks.len() - leftover].0,
+        ));
+    }
+
+    lints
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn odd_rucksacks_and_groups_are_flagged() {
+        let lints = lints("abcab\nvJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\nPmmdzqPrVvPwwTWBwg\n");
+
+        assert_eq!(
+            lints
+                .iter()
+                .map(|l| (l.rule, l.location.as_ref().unwrap().line))
+                .collect::<Vec<_>>(),
+            vec![
+                ("odd-rucksack", 1),
+                ("shared-items", 1),
+                ("incomplete-group", 4)
+            ]
+        );
+    }
+}
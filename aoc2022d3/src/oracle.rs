@@ -0,0 +1,71 @@
+//! Deliberately naive reference solutions, for differential tests.
+
+use crate::{Item, Rucksack};
+
+/// Every item type, in priority order.
+const TYPES: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+fn priority(t: char) -> u64 {
+    TYPES.find(t).unwrap() as u64 + 1
+}
+
+fn holds(items: &[Item], t: char) -> bool {
+    items.iter().any(|&(_, other)| other == t)
+}
+
+/// Try every item type against both compartments.
+pub fn part1(rucksacks: &[Rucksack]) -> u64 {
+    rucksacks
+        .iter()
+        .map(|(left, right)| {
+            TYPES
+                .chars()
+                .filter(|&t| holds(left, t) && holds(right, t))
+                .map(priority)
+                .sum::<u64>()
+        })
+        .sum()
+}
+
+/// Try every item type against all three rucksacks of each group.
+pub fn part2(rucksacks: &[Rucksack]) -> Option<u64> {
+    if !rucksacks.len().is_multiple_of(3) {
+        return None;
+    }
+
+    let badges = rucksacks
+        .chunks(3)
+        .map(|group| {
+            TYPES
+                .chars()
+                .filter(|&t| {
+                    group
+                        .iter()
+                        .all(|(left, right)| holds(left, t) || holds(right, t))
+                })
+                .map(priority)
+                .sum::<u64>()
+        })
+        .sum();
+
+    Some(badges)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Day3, Input};
+    use aoc_core::{oracle::differential, Answer, Part};
+
+    #[test]
+    fn part1_agrees() {
+        differential(&Day3, Part::One, |input: &Input| Some(part1(input).into()));
+    }
+
+    #[test]
+    fn part2_agrees() {
+        differential(&Day3, Part::Two, |input: &Input| {
+            part2(input).map(Answer::from)
+        });
+    }
+}
@@ -0,0 +1,46 @@
+use crate::Rucksack;
+
+/// Print `rucksacks` back in the puzzle format, one per line with both
+/// compartments run together.
+pub fn input(rucksacks: &[Rucksack]) -> String {
+    rucksacks
+        .iter()
+        .map(|(left, right)| {
+            let mut line = left
+                .iter()
+                .chain(right)
+                .map(|&(_, t)| t)
+                .collect::<String>();
+            line.push('\n');
+            line
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{char_to_priority, parse, Compartment};
+    use proptest::prelude::*;
+
+    fn compartment(len: usize) -> impl Strategy<Value = Compartment> {
+        let letters = ('a'..='z').chain('A'..='Z').collect::<Vec<_>>();
+        let item = prop::sample::select(letters).prop_map(|t| (char_to_priority(t), t));
+
+        prop::collection::vec(item, len)
+    }
+
+    /// Parsing splits a rucksack in the middle, so both halves have the
+    /// same length.
+    fn rucksack() -> impl Strategy<Value = Rucksack> {
+        (1..20usize).prop_flat_map(|len| (compartment(len), compartment(len)))
+    }
+
+    proptest! {
+        #[test]
+        fn parse_reverses_print(rucksacks in prop::collection::vec(rucksack(), 1..20)) {
+            let text = input(&rucksacks);
+            prop_assert_eq!(parse(&text).unwrap(), rucksacks);
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use aoc2022d4::Day4;
+use aoc_core::bench::criterion::{criterion_group, criterion_main, Criterion};
+
+fn day4(c: &mut Criterion) {
+    aoc_core::bench::solution(c, &Day4);
+
+    #[cfg(feature = "bytes")]
+    aoc_core::bench::bytes(c, &Day4, aoc2022d4::bytes::parse);
+}
+
+criterion_group!(benches, day4);
+criterion_main!(benches);
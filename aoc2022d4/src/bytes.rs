@@ -0,0 +1,36 @@
+use crate::Input;
+use aoc_core::AocError;
+use nom::{
+    character::complete::{self, char, line_ending},
+    combinator::map,
+    multi::many1,
+    sequence::{separated_pair, terminated},
+    IResult,
+};
+use std::ops::RangeInclusive;
+
+fn range(input: &[u8]) -> IResult<&[u8], RangeInclusive<u32>> {
+    map(
+        separated_pair(complete::u32, char('-'), complete::u32),
+        |(a, b)| a..=b,
+    )(input)
+}
+
+fn pairs(input: &[u8]) -> IResult<&[u8], Input> {
+    many1(terminated(
+        separated_pair(range, char(','), range),
+        line_ending,
+    ))(input)
+}
+
+/// Byte level [`crate::parse`].
+pub fn parse(input: &[u8]) -> Result<Input, AocError> {
+    aoc_core::bytes::finish(input, pairs)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    aoc_core::bytes_agree!(crate::parse; "2-4,6-8\n2-3;4-5\n", "2-4, 6-8\n", "2-4\n");
+}
@@ -0,0 +1,35 @@
+use aoc_core::gen::Rng;
+
+fn range(rng: &mut Rng) -> String {
+    let start = rng.range(1..=99);
+    let end = rng.range(start..=99);
+
+    format!("{}-{}", start, end)
+}
+
+/// `size` pairs of section ranges, none of them reversed.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| format!("{},{}\n", range(rng), range(rng)))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{parse, part1, part2};
+
+    #[test]
+    fn generated_inputs_solve() {
+        for seed in 0..20 {
+            let input = input(&mut Rng::new(seed), 50);
+            let pairs = parse(&input).unwrap();
+
+            assert_eq!(pairs.len(), 50);
+            assert!(pairs
+                .iter()
+                .all(|(a, b)| a.start() <= a.end() && b.start() <= b.end()));
+            assert!(part1(&pairs) <= part2(&pairs));
+        }
+    }
+}
@@ -1,73 +1,129 @@
-use clap::Parser;
+#[cfg(feature = "bytes")]
+pub mod bytes;
+pub mod gen;
+pub mod lint;
+pub mod oracle;
+pub mod print;
+pub mod stream;
+
+use aoc_core::{
+    gen::Rng, input::Source, lint::Lint, recover, Answer, AocError, ParseResult, Part, Solution,
+    Span,
+};
 use nom::{
-    character::complete::{self, line_ending},
+    character::complete::{self, line_ending, space0},
     combinator::map,
+    error::context,
     multi::many1,
-    sequence::{separated_pair, terminated},
-    IResult,
+    sequence::{delimited, separated_pair, terminated},
 };
+use std::io::BufRead;
 use std::ops::RangeInclusive;
 
-#[derive(Parser, Debug)]
-#[command(
-    name = "aoc2022d4",
-    author = "Felipe Balbi <felipe@balbi.sh>",
-    about = "Advent of Code 2022 Day 4",
-    version = "0.1.0"
-)]
-pub struct Cli {
-    #[arg(short = '1', long = "part1", help = "Run part 1.")]
-    part1: bool,
-
-    #[arg(short = '2', long = "part2", help = "Run part 2.")]
-    part2: bool,
-}
+pub type Pair = (RangeInclusive<u32>, RangeInclusive<u32>);
 
-impl Cli {
-    pub fn run(&self, input: &str) {
-        if self.part1 {
-            println!("Part 1: {}", part1(input));
-        }
+/// The parsed puzzle input.
+pub type Input = Vec<Pair>;
 
-        if self.part2 {
-            println!("Part 2: {}", part2(input));
-        }
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input<'a> = Input;
+
+    fn name(&self) -> &'static str {
+        "aoc2022d4"
+    }
+
+    fn year(&self) -> u32 {
+        2022
+    }
+
+    fn day(&self) -> u32 {
+        4
+    }
+
+    fn manifest_dir(&self) -> &'static str {
+        env!("CARGO_MANIFEST_DIR")
+    }
+
+    fn parse_span<'a>(&self, input: Span<'a>) -> ParseResult<'a, Self::Input<'a>> {
+        parse_input(input)
+    }
+
+    fn parse_recovering<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, AocError> {
+        let (ranges, report) = recover::lines(Span::new(input), parse_line_loosely);
+        report.finish(ranges.into_iter().map(|(_, pair)| pair).collect())
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input).into())
+    }
+
+    fn stream(
+        &self,
+        source: &Source,
+        reader: &mut dyn BufRead,
+        parts: &[Part],
+    ) -> Option<Result<Vec<(Part, Answer)>, AocError>> {
+        Some(stream::solve(source, reader, parts))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        Some(gen::input(rng, size))
     }
+
+    fn print(&self, input: &Self::Input<'_>) -> Option<String> {
+        Some(print::input(input))
+    }
+
+    fn lint(&self, input: &str) -> Vec<Lint> {
+        lint::lints(input)
+    }
+}
+
+/// Parse the section assignments, one pair of ranges per line.
+pub fn parse(input: &str) -> Result<Input, AocError> {
+    Day4.parse(input)
 }
 
-fn parse_range(input: &str) -> IResult<&str, RangeInclusive<u32>> {
+fn parse_range(input: Span<'_>) -> ParseResult<'_, RangeInclusive<u32>> {
     map(
         separated_pair(complete::u32, complete::char('-'), complete::u32),
         |(a, b)| a..=b,
     )(input)
 }
 
-fn parse_line(input: &str) -> IResult<&str, (RangeInclusive<u32>, RangeInclusive<u32>)> {
-    separated_pair(parse_range, complete::char(','), parse_range)(input)
+fn parse_line(input: Span<'_>) -> ParseResult<'_, Pair> {
+    context(
+        "`A-B,C-D`",
+        separated_pair(parse_range, complete::char(','), parse_range),
+    )(input)
 }
 
-fn parse_input_part1(
-    input: &str,
-) -> IResult<&str, Vec<(RangeInclusive<u32>, RangeInclusive<u32>)>> {
-    many1(terminated(parse_line, line_ending))(input)
+/// Like [`parse_line`], but lets spaces around the comma through, so that
+/// `aoc fmt` can drop the ones hand-written inputs pick up.
+fn parse_line_loosely(input: Span<'_>) -> ParseResult<'_, Pair> {
+    let comma = delimited(space0, complete::char(','), space0);
+
+    context("`A-B,C-D`", separated_pair(parse_range, comma, parse_range))(input)
 }
 
-fn parse_input_part2(
-    input: &str,
-) -> IResult<&str, Vec<(RangeInclusive<u32>, RangeInclusive<u32>)>> {
-    parse_input_part1(input)
+fn parse_input(input: Span<'_>) -> ParseResult<'_, Vec<Pair>> {
+    many1(terminated(parse_line, line_ending))(input)
 }
 
 fn fully_contains(a: &RangeInclusive<u32>, b: &RangeInclusive<u32>) -> bool {
     a.start() <= b.start() && a.end() >= b.end()
 }
 
-fn part1(input: &str) -> usize {
-    let (_, ranges) = parse_input_part1(input).unwrap();
-
+pub fn part1(ranges: &[Pair]) -> usize {
     ranges
         .iter()
-        .filter(|(a, b)| fully_contains(&a, &b) || fully_contains(&b, &a))
+        .filter(|(a, b)| fully_contains(a, b) || fully_contains(b, a))
         .count()
 }
 
@@ -75,12 +131,10 @@ fn overlaps(a: &RangeInclusive<u32>, b: &RangeInclusive<u32>) -> bool {
     a.start() <= b.end() && a.end() >= b.start()
 }
 
-fn part2(input: &str) -> usize {
-    let (_, ranges) = parse_input_part2(input).unwrap();
-
+pub fn part2(ranges: &[Pair]) -> usize {
     ranges
         .iter()
-        .filter(|(a, b)| overlaps(&a, &b) || overlaps(&b, &a))
+        .filter(|(a, b)| overlaps(a, b) || overlaps(b, a))
         .count()
 }
 
@@ -88,29 +142,58 @@ fn part2(input: &str) -> usize {
 mod test {
     use super::*;
 
+    aoc_core::verify_answers!(Day4);
+    aoc_core::verify_examples!(Day4);
+
     #[test]
-    fn part1_works() {
+    fn recovering_reports_every_line() {
         let input = "2-4,6-8
-2-3,4-5
+2-3;4-5
 5-7,7-9
-2-8,3-7
-6-6,4-6
-2-6,4-8
+2-8,3-x
 ";
 
-        assert_eq!(part1(input), 2);
+        match Day4.parse_recovering(input) {
+            Err(AocError::Malformed(report)) => {
+                assert_eq!(report.to_string(), "2 malformed lines, 2 of 4 lines parsed");
+                assert_eq!(
+                    report
+                        .errors
+                        .iter()
+                        .map(|e| e.to_string())
+                        .collect::<Vec<_>>(),
+                    vec!["expected `A-B,C-D` at 2:4", "expected `A-B,C-D` at 4:7"]
+                );
+            }
+            other => panic!("expected a malformed report, got {:?}", other),
+        }
     }
 
     #[test]
-    fn part2_works() {
-        let input = "2-4,6-8
-2-3,4-5
-5-7,7-9
-2-8,3-7
-6-6,4-6
-2-6,4-8
-";
+    fn touching_ranges_overlap() {
+        let pairs = vec![(1..=3, 3..=5), (4..=4, 4..=4), (1..=2, 4..=5)];
 
-        assert_eq!(part2(input), 4);
+        assert_eq!(part1(&pairs), 1);
+        assert_eq!(part2(&pairs), 2);
+    }
+
+    #[test]
+    fn format_drops_stray_spaces() {
+        let input = "2-4, 6-8\n2-3 ,4-5\r\n";
+
+        assert_eq!(
+            aoc_core::Runner::format(&Day4, input).unwrap(),
+            "2-4,6-8\n2-3,4-5\n"
+        );
+        assert!(matches!(
+            aoc_core::Runner::format(&Day4, "2-4,6-8\n2-3,4 -5\n3-4\n"),
+            Err(AocError::Malformed(report)) if report.errors.len() == 2
+        ));
+    }
+
+    #[test]
+    fn parse_rejects_spaces_around_the_comma() {
+        assert!(matches!(parse("2-4, 6-8\n"), Err(AocError::Parse(_))));
+        assert!(matches!(parse("2-4 ,6-8\n"), Err(AocError::Parse(_))));
     }
 }
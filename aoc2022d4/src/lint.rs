@@ -0,0 +1,51 @@
+use crate::parse_range;
+use aoc_core::lint::{marked, Lint, Severity};
+use aoc_core::{recover, Span};
+use nom::{character::complete, sequence::separated_pair};
+
+pub fn lints(input: &str) -> Vec<Lint> {
+    let ranges = separated_pair(
+        marked(parse_range),
+        complete::char(','),
+        marked(parse_range),
+    );
+    let (pairs, _) = recover::lines(Span::new(input), ranges);
+
+    pairs
+        .into_iter()
+        .flat_map(|(_, (a, b))| [a, b])
+        .filter(|(_, range)| range.start() > range.end())
+        .map(|(span, range)| {
+            Lint::at(
+                Severity::Warning,
+                "reversed-range",
+                format!(
+                    "`{}-{}` is empty, so it never contains or overlaps anything",
+                    range.start(),
+                    range.end()
+                ),
+                &span,
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn reversed_ranges_are_flagged() {
+        let lints = lints("2-4,6-8\n2-3,8-2\n");
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!(
+            lints[0].location,
+            Some(aoc_core::Location {
+                line: 2,
+                column: 5,
+                source_line: "2-3,8-2".to_owned(),
+            })
+        );
+    }
+}
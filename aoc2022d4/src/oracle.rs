@@ -0,0 +1,41 @@
+//! Deliberately naive reference solutions, for differential tests.
+
+use crate::Pair;
+
+/// Compare the sections themselves rather than the range bounds.
+fn sections((a, b): &Pair) -> (Vec<u32>, Vec<u32>) {
+    (a.clone().collect(), b.clone().collect())
+}
+
+pub fn part1(pairs: &[Pair]) -> usize {
+    pairs
+        .iter()
+        .map(sections)
+        .filter(|(a, b)| a.iter().all(|n| b.contains(n)) || b.iter().all(|n| a.contains(n)))
+        .count()
+}
+
+pub fn part2(pairs: &[Pair]) -> usize {
+    pairs
+        .iter()
+        .map(sections)
+        .filter(|(a, b)| a.iter().any(|n| b.contains(n)))
+        .count()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Day4, Input};
+    use aoc_core::{oracle::differential, Part};
+
+    #[test]
+    fn part1_agrees() {
+        differential(&Day4, Part::One, |input: &Input| Some(part1(input).into()));
+    }
+
+    #[test]
+    fn part2_agrees() {
+        differential(&Day4, Part::Two, |input: &Input| Some(part2(input).into()));
+    }
+}
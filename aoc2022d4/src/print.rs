@@ -0,0 +1,28 @@
+use crate::Pair;
+
+/// Print `pairs` back in the puzzle format.
+pub fn input(pairs: &[Pair]) -> String {
+    pairs
+        .iter()
+        .map(|(a, b)| format!("{}-{},{}-{}\n", a.start(), a.end(), b.start(), b.end()))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parse;
+    use proptest::prelude::*;
+
+    fn pair() -> impl Strategy<Value = Pair> {
+        any::<(u32, u32, u32, u32)>().prop_map(|(a, b, c, d)| (a..=b, c..=d))
+    }
+
+    proptest! {
+        #[test]
+        fn parse_reverses_print(pairs in prop::collection::vec(pair(), 1..50)) {
+            let text = input(&pairs);
+            prop_assert_eq!(parse(&text).unwrap(), pairs);
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{fully_contains, overlaps, Pair};
+use aoc_core::{input::Source, stream::Stream, Answer, AocError, Part};
+use nom::{
+    character::streaming::{self, char, line_ending},
+    combinator::map,
+    sequence::{separated_pair, terminated},
+    IResult,
+};
+use std::io::BufRead;
+use std::ops::RangeInclusive;
+
+fn range(input: &[u8]) -> IResult<&[u8], RangeInclusive<u32>> {
+    map(
+        separated_pair(streaming::u32, char('-'), streaming::u32),
+        |(a, b)| a..=b,
+    )(input)
+}
+
+fn pair(input: &[u8]) -> IResult<&[u8], Pair> {
+    terminated(separated_pair(range, char(','), range), line_ending)(input)
+}
+
+/// Every pair of ranges in `reader`, one at a time.
+pub fn pairs<R: BufRead>(
+    source: &Source,
+    reader: R,
+) -> impl Iterator<Item = Result<Pair, AocError>> {
+    Stream::new(source, reader, pair)
+}
+
+/// Solve `parts` in one pass, counting as it goes.
+pub fn solve<R: BufRead>(
+    source: &Source,
+    reader: R,
+    parts: &[Part],
+) -> Result<Vec<(Part, Answer)>, AocError> {
+    let (mut contained, mut overlapping) = (0usize, 0usize);
+
+    for pair in pairs(source, reader) {
+        let (a, b) = pair?;
+        if fully_contains(&a, &b) || fully_contains(&b, &a) {
+            contained += 1;
+        }
+        if overlaps(&a, &b) {
+            overlapping += 1;
+        }
+    }
+
+    Ok(parts
+        .iter()
+        .map(|&part| match part {
+            Part::One => (part, contained.into()),
+            Part::Two => (part, overlapping.into()),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::BufReader;
+
+    #[test]
+    fn solve_works() {
+        let input = "2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8";
+        let reader = BufReader::with_capacity(5, input.as_bytes());
+
+        assert_eq!(
+            solve(&Source::Stdin, reader, &[Part::One, Part::Two]).unwrap(),
+            vec![(Part::One, 2usize.into()), (Part::Two, 4usize.into())]
+        );
+    }
+}
@@ -0,0 +1,12 @@
+use aoc2022d5::Day5;
+use aoc_core::bench::criterion::{criterion_group, criterion_main, Criterion};
+
+fn day5(c: &mut Criterion) {
+    aoc_core::bench::solution(c, &Day5);
+
+    #[cfg(feature = "bytes")]
+    aoc_core::bench::bytes(c, &Day5, aoc2022d5::bytes::parse);
+}
+
+criterion_group!(benches, day5);
+criterion_main!(benches);
@@ -0,0 +1,87 @@
+use crate::{transpose_crates, Input, Move};
+use aoc_core::AocError;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{self, alpha1, line_ending, space0, space1},
+    combinator::{map, map_res, value, verify},
+    multi::separated_list1,
+    sequence::{delimited, tuple},
+    IResult,
+};
+use std::str;
+
+fn krate(input: &[u8]) -> IResult<&[u8], Option<&str>> {
+    alt((
+        map(
+            delimited(tag("["), map_res(alpha1, str::from_utf8), tag("]")),
+            Some,
+        ),
+        value(None, tag("   ")),
+    ))(input)
+}
+
+fn crates(input: &[u8]) -> IResult<&[u8], Vec<Vec<&str>>> {
+    map(
+        separated_list1(line_ending, separated_list1(tag(" "), krate)),
+        transpose_crates,
+    )(input)
+}
+
+fn crate_ids(input: &[u8]) -> IResult<&[u8], ()> {
+    value(
+        (),
+        tuple((
+            line_ending,
+            space0,
+            separated_list1(space1, complete::u32),
+            space0,
+            line_ending,
+            line_ending,
+        )),
+    )(input)
+}
+
+fn stack(input: &[u8]) -> IResult<&[u8], usize> {
+    map(verify(complete::u32, |n| *n > 0), |n| (n - 1) as usize)(input)
+}
+
+fn mov(input: &[u8]) -> IResult<&[u8], Move> {
+    map(
+        tuple((
+            tag("move "),
+            complete::u32,
+            tag(" from "),
+            stack,
+            tag(" to "),
+            stack,
+        )),
+        |(_, amount, _, from, _, to)| Move {
+            amount: amount as usize,
+            from,
+            to,
+        },
+    )(input)
+}
+
+fn drawing(input: &[u8]) -> IResult<&[u8], Input<'_>> {
+    map(
+        tuple((crates, crate_ids, separated_list1(line_ending, mov))),
+        |(crates, _, moves)| (crates, moves),
+    )(input)
+}
+
+/// Byte level [`crate::parse`].
+pub fn parse(input: &[u8]) -> Result<Input<'_>, AocError> {
+    aoc_core::bytes::finish(input, drawing)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    aoc_core::bytes_agree!(crate::parse;
+        "[A]\n 1 \n\nmove 1 from 0 to 1\n",
+        "[A]\n 1 \n\nmove x from 1 to 1\n"
+    );
+}
@@ -0,0 +1,65 @@
+use crate::print::drawing;
+use crate::Move;
+use aoc_core::gen::Rng;
+
+/// Every crate label, one letter each.
+pub(crate) const CRATES: [&str; 26] = [
+    "A", "B", "C", "D", "E", "F", "G", "H", "I", "J", "K", "L", "M", "N", "O", "P", "Q", "R", "S",
+    "T", "U", "V", "W", "X", "Y", "Z",
+];
+
+/// Up to nine stacks and `size` moves. Moves are simulated as they are
+/// generated, so none takes more crates than its stack holds and no
+/// stack is ever emptied.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let mut stacks = (0..rng.range(2..=9))
+        .map(|_| {
+            (0..rng.range(1..=8))
+                .map(|_| *rng.pick(&CRATES))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    // Some stack needs a crate to spare, or nothing could ever move.
+    if stacks.iter().all(|stack| stack.len() < 2) {
+        stacks[0].push("Z");
+    }
+
+    let drawing = drawing(&stacks);
+    let mut moves = String::new();
+
+    for _ in 0..size.max(1) {
+        let sources = (0..stacks.len())
+            .filter(|&i| stacks[i].len() >= 2)
+            .collect::<Vec<_>>();
+        let from = *rng.pick(&sources);
+        let to = (from + rng.range(1..=stacks.len() - 1)) % stacks.len();
+        let amount = rng.range(1..=stacks[from].len() - 1);
+
+        let at = stacks[from].len() - amount;
+        let crates = stacks[from].split_off(at);
+        stacks[to].extend(crates);
+
+        moves.push_str(&format!("{}\n", Move { amount, from, to }));
+    }
+
+    format!("{}\n\n{}", drawing, moves)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{parse, part1, part2};
+
+    #[test]
+    fn generated_inputs_solve() {
+        for seed in 0..50 {
+            let input = input(&mut Rng::new(seed), 30);
+            let parsed = parse(&input).unwrap();
+
+            assert_eq!(parsed.1.len(), 30);
+            assert_eq!(part1(&parsed).unwrap().len(), parsed.0.len());
+            assert_eq!(part2(&parsed).unwrap().len(), parsed.0.len());
+        }
+    }
+}
@@ -1,111 +1,185 @@
-use clap::Parser;
+#[cfg(feature = "bytes")]
+pub mod bytes;
+pub mod gen;
+pub mod lint;
+pub mod oracle;
+pub mod print;
+
+use aoc_core::{
+    gen::Rng, lint::Lint, recover, shrink::Split, Answer, AocError, ParseResult, Solution, Span,
+};
 use nom::{
     branch::alt,
-    bytes::complete::{tag, take_until},
-    character::complete::{self, alpha1, line_ending},
-    combinator::{map, value},
+    bytes::complete::tag,
+    character::complete::{self, alpha1, line_ending, space0, space1},
+    combinator::{map, value, verify},
+    error::context,
     multi::separated_list1,
-    sequence::{delimited, pair, tuple},
-    IResult,
+    sequence::{delimited, tuple},
 };
+use serde::Serialize;
 
-#[derive(Parser, Debug)]
-#[command(
-    name = "aoc2022d5",
-    author = "Felipe Balbi <felipe@balbi.sh>",
-    about = "Advent of Code 2022 Day 5",
-    version = "0.1.0"
-)]
-pub struct Cli {
-    #[arg(short = '1', long = "part1", help = "Run part 1.")]
-    part1: bool,
-
-    #[arg(short = '2', long = "part2", help = "Run part 2.")]
-    part2: bool,
-}
-
-impl Cli {
-    pub fn run(&self, input: &str) {
-        if self.part1 {
-            println!("Part 1: {}", part1(input));
-        }
+/// The parsed puzzle input.
+pub type Input<'a> = (Vec<Vec<&'a str>>, Vec<Move>);
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input<'a> = Input<'a>;
+
+    fn name(&self) -> &'static str {
+        "aoc2022d5"
+    }
+
+    fn year(&self) -> u32 {
+        2022
+    }
+
+    fn day(&self) -> u32 {
+        5
+    }
+
+    fn manifest_dir(&self) -> &'static str {
+        env!("CARGO_MANIFEST_DIR")
+    }
+
+    fn parse_span<'a>(&self, input: Span<'a>) -> ParseResult<'a, Self::Input<'a>> {
+        parse_input(input)
+    }
+
+    /// The crate drawing is not line oriented, so it is still parsed
+    /// strictly; only the moves below it recover.
+    fn parse_recovering<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, AocError> {
+        let (input, crates) = parse_crates(Span::new(input))?;
+        let (input, _) = parse_crate_id(input)?;
+        let (moves, report) = recover::lines(input, parse_move);
+
+        report.finish((crates, moves.into_iter().map(|(_, m)| m).collect()))
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        part2(input).map(Answer::from)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        Some(gen::input(rng, size))
+    }
 
-        if self.part2 {
-            println!("Part 2: {}", part2(input));
+    fn splits(&self, input: &str) -> Vec<Split> {
+        match input.split_once("\n\n") {
+            Some((drawing, moves)) => vec![Split::lines(moves).after(&format!("{}\n\n", drawing))],
+            None => vec![Split::lines(input)],
         }
     }
+
+    fn print(&self, input: &Self::Input<'_>) -> Option<String> {
+        Some(print::input(input))
+    }
+
+    fn lint(&self, input: &str) -> Vec<Lint> {
+        lint::lints(input)
+    }
 }
 
-#[derive(Debug)]
-struct Move {
-    amount: usize,
-    from: usize,
-    to: usize,
+/// Parse the crate drawing into stacks, bottom crate first, and the
+/// moves into 0-based stack indices.
+pub fn parse(input: &str) -> Result<Input<'_>, AocError> {
+    Day5.parse(input)
 }
 
-fn parse_crate(input: &str) -> IResult<&str, Option<&str>> {
-    map(
-        alt((delimited(tag("["), alpha1, tag("]")), tag("   "))),
-        |krate| match krate {
-            "   " => None,
-            k => Some(k),
-        },
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Move {
+    pub amount: usize,
+    /// Zero based, unlike in the puzzle text.
+    pub from: usize,
+    /// Zero based, unlike in the puzzle text.
+    pub to: usize,
+}
+
+fn parse_crate<'a>(input: Span<'a>) -> ParseResult<'a, Option<&'a str>> {
+    context(
+        "`[X]` or three spaces",
+        map(
+            alt((delimited(tag("["), alpha1, tag("]")), tag("   "))),
+            |krate: Span<'a>| match *krate.fragment() {
+                "   " => None,
+                k => Some(k),
+            },
+        ),
     )(input)
 }
 
-fn parse_line(input: &str) -> IResult<&str, Vec<Option<&str>>> {
+fn parse_line<'a>(input: Span<'a>) -> ParseResult<'a, Vec<Option<&'a str>>> {
     separated_list1(tag(" "), parse_crate)(input)
 }
 
 fn transpose_crates(crates: Vec<Vec<Option<&str>>>) -> Vec<Vec<&str>> {
-    let len = crates[0].len();
+    let len = crates.iter().map(|row| row.len()).max().unwrap_or(0);
     let mut iters: Vec<_> = crates.into_iter().map(|n| n.into_iter()).collect();
     (0..len)
         .map(|_| {
             iters
                 .iter_mut()
-                .map(|n| n.next().unwrap())
-                .filter(|n| n.is_some())
-                .map(|n| n.unwrap())
+                .filter_map(|n| n.next().flatten())
                 .rev()
                 .collect::<Vec<_>>()
         })
         .collect()
 }
 
-fn parse_crates(input: &str) -> IResult<&str, Vec<Vec<&str>>> {
+fn parse_crates<'a>(input: Span<'a>) -> ParseResult<'a, Vec<Vec<&'a str>>> {
     let (input, crates) = separated_list1(line_ending, parse_line)(input)?;
     let crates = transpose_crates(crates);
     Ok((input, crates))
 }
 
-fn parse_crate_id(input: &str) -> IResult<&str, ()> {
-    value((), pair(take_until("\n\n"), tag("\n\n")))(input)
-}
-
-fn parse_move(input: &str) -> IResult<&str, Move> {
-    map(
-        tuple((
-            tag("move "),
-            complete::u32,
-            tag(" from "),
-            complete::u32,
-            tag(" to "),
-            complete::u32,
-        )),
-        |(_, amount, _, from, _, to)| Move {
-            amount: amount as usize,
-            from: (from - 1) as usize,
-            to: (to - 1) as usize,
-        },
+fn parse_crate_id(input: Span<'_>) -> ParseResult<'_, ()> {
+    context(
+        "a line of stack numbers followed by a blank line",
+        value(
+            (),
+            tuple((
+                line_ending,
+                space0,
+                separated_list1(space1, complete::u32),
+                space0,
+                line_ending,
+                line_ending,
+            )),
+        ),
     )(input)
 }
 
-fn parse_moves(input: &str) -> IResult<&str, Vec<Move>> {
+fn parse_move(input: Span<'_>) -> ParseResult<'_, Move> {
+    context(
+        "`move N from A to B`",
+        map(
+            tuple((
+                tag("move "),
+                complete::u32,
+                tag(" from "),
+                verify(complete::u32, |n| *n > 0),
+                tag(" to "),
+                verify(complete::u32, |n| *n > 0),
+            )),
+            |(_, amount, _, from, _, to)| Move {
+                amount: amount as usize,
+                from: (from - 1) as usize,
+                to: (to - 1) as usize,
+            },
+        ),
+    )(input)
+}
+
+fn parse_moves(input: Span<'_>) -> ParseResult<'_, Vec<Move>> {
     separated_list1(line_ending, parse_move)(input)
 }
 
-fn parse_input_part1(input: &str) -> IResult<&str, (Vec<Vec<&str>>, Vec<Move>)> {
+fn parse_input<'a>(input: Span<'a>) -> ParseResult<'a, Input<'a>> {
     let (input, crates) = parse_crates(input)?;
     let (input, _) = parse_crate_id(input)?;
     let (input, moves) = parse_moves(input)?;
@@ -113,14 +187,52 @@ fn parse_input_part1(input: &str) -> IResult<&str, (Vec<Vec<&str>>, Vec<Move>)>
     Ok((input, (crates, moves)))
 }
 
-fn parse_input_part2(input: &str) -> IResult<&str, (Vec<Vec<&str>>, Vec<Move>)> {
-    parse_input_part1(input)
+/// Make sure `m` only touches existing stacks and never takes more
+/// crates than its source stack holds.
+fn check_move(crates: &[Vec<&str>], m: &Move) -> Result<(), AocError> {
+    for stack in [m.from, m.to] {
+        if stack >= crates.len() {
+            return Err(AocError::Validation(format!(
+                "stack {} does not exist, there are only {}",
+                stack + 1,
+                crates.len()
+            )));
+        }
+    }
+
+    if m.amount > crates[m.from].len() {
+        return Err(AocError::Validation(format!(
+            "cannot move {} crates from stack {} holding {}",
+            m.amount,
+            m.from + 1,
+            crates[m.from].len()
+        )));
+    }
+
+    Ok(())
 }
 
-fn part1(input: &str) -> String {
-    let (_, (mut crates, moves)) = parse_input_part1(input).unwrap();
+fn top_crates(crates: &[Vec<&str>]) -> Result<String, AocError> {
+    let msg = crates
+        .iter()
+        .enumerate()
+        .map(|(i, krate)| {
+            krate
+                .last()
+                .copied()
+                .ok_or_else(|| AocError::Validation(format!("stack {} ends up empty", i + 1)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(msg.join(""))
+}
+
+pub fn part1((crates, moves): &Input) -> Result<String, AocError> {
+    let mut crates = crates.clone();
 
     for m in moves {
+        check_move(&crates, m)?;
+
         for _ in 0..m.amount {
             if let Some(krate) = crates[m.from].pop() {
                 crates[m.to].push(krate);
@@ -128,64 +240,149 @@ fn part1(input: &str) -> String {
         }
     }
 
-    let msg = crates
-        .iter()
-        .map(|krate| krate.last().unwrap().clone())
-        .collect::<Vec<_>>();
-
-    msg.join("")
+    top_crates(&crates)
 }
 
-fn part2(input: &str) -> String {
-    let (_, (mut crates, moves)) = parse_input_part2(input).unwrap();
+pub fn part2((crates, moves): &Input) -> Result<String, AocError> {
+    let mut crates = crates.clone();
 
     for m in moves {
+        check_move(&crates, m)?;
+
         let len = crates[m.from].len();
         let tail = crates[m.from].split_off(len - m.amount);
         crates[m.to].extend(tail);
     }
 
-    let msg = crates
-        .iter()
-        .map(|krate| krate.last().unwrap().clone())
-        .collect::<Vec<_>>();
-
-    msg.join("")
+    top_crates(&crates)
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use aoc_core::{Dump, Options, Part, Runner};
+
+    aoc_core::verify_answers!(Day5);
+    aoc_core::verify_examples!(Day5);
+
+    #[test]
+    fn windows_line_endings_work() {
+        let input = "\u{feff}    [D]    \r\n[N] [C]    \r\n[Z] [M] [P]\r\n 1   2   3 \r\n\r\n\
+                     move 1 from 2 to 1\r\nmove 3 from 1 to 3\r\n\
+                     move 2 from 2 to 1\r\nmove 1 from 1 to 2\r\n\r\n";
+
+        let run = Day5
+            .run(input, &[Part::One, Part::Two], &Options::default())
+            .unwrap();
+
+        assert_eq!(run.answer(Part::One).unwrap(), &"CMZ");
+        assert_eq!(run.answer(Part::Two).unwrap(), &"MCD");
+    }
 
     #[test]
-    fn part1_works() {
-        let input = "    [D]    
-[N] [C]    
-[Z] [M] [P]
- 1   2   3 
-
-move 1 from 2 to 1
-move 3 from 1 to 3
-move 2 from 2 to 1
-move 1 from 1 to 2
+    fn dump_shows_stacks_and_moves() {
+        let input = "[A]    
+[B] [C]
+ 1   2 
+
+move 2 from 1 to 2
 ";
 
-        assert_eq!(part1(input), "CMZ");
+        assert_eq!(
+            Day5.dump(input, &Options::default(), Dump::Debug).unwrap(),
+            "(
+    [
+        [
+            \"B\",
+            \"A\",
+        ],
+        [
+            \"C\",
+        ],
+    ],
+    [
+        Move {
+            amount: 2,
+            from: 0,
+            to: 1,
+        },
+    ],
+)"
+        );
     }
 
     #[test]
-    fn part2_works() {
-        let input = "    [D]    
-[N] [C]    
-[Z] [M] [P]
- 1   2   3 
-
-move 1 from 2 to 1
-move 3 from 1 to 3
-move 2 from 2 to 1
-move 1 from 1 to 2
+    fn impossible_move_fails() {
+        let input = "[A]    
+[B] [C]
+ 1   2 
+
+move 3 from 1 to 2
 ";
+        let parsed = Day5.parse(input).unwrap();
+
+        assert!(matches!(Day5.part1(&parsed), Err(AocError::Validation(_))));
+        assert!(matches!(Day5.part2(&parsed), Err(AocError::Validation(_))));
+    }
+
+    #[test]
+    fn bad_move_is_located() {
+        let input = "[A]
+ 1 
+
+move x from 1 to 1
+";
+
+        match Day5.parse(input) {
+            Err(AocError::Parse(diagnostic)) => {
+                assert_eq!(
+                    diagnostic.to_string(),
+                    "expected `move N from A to B` at 4:6"
+                )
+            }
+            other => panic!("expected a parse error, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn moves_use_zero_based_stacks() {
+        let input = (
+            vec![vec!["Z", "N"], vec!["M", "C", "D"], vec!["P"]],
+            vec![Move {
+                amount: 2,
+                from: 1,
+                to: 0,
+            }],
+        );
+
+        assert_eq!(part1(&input).unwrap(), "CMP");
+        assert_eq!(part2(&input).unwrap(), "DMP");
+    }
+
+    #[test]
+    fn format_restores_trailing_spaces() {
+        let input = "    [D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3\n\nmove 1 from 2 to 1\n";
+
+        assert_eq!(
+            Runner::format(&Day5, input).unwrap(),
+            "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\n"
+        );
+    }
+
+    #[test]
+    fn splits_keep_the_drawing() {
+        let input = "[A]\n 1 \n\nmove 1 from 1 to 1\nmove 1 from 1 to 1\n";
+        let splits = Solution::splits(&Day5, input);
 
-        assert_eq!(part2(input), "MCD");
+        assert_eq!(splits.len(), 1);
+        assert_eq!(splits[0].pieces.len(), 2);
+        assert_eq!(
+            (splits[0].join)(&splits[0].pieces[1..]),
+            "[A]\n 1 \n\nmove 1 from 1 to 1\n"
+        );
+        assert_eq!(
+            Solution::splits(&Day5, "[A]\n 1 \n")[0].pieces,
+            ["[A]", " 1 "]
+        );
     }
 }
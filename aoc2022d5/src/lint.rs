@@ -0,0 +1,120 @@
+use crate::parse_crates;
+use aoc_core::lint::{marked, Lint, Severity};
+use aoc_core::{recover, ParseResult, Span};
+use nom::{
+    bytes::complete::tag,
+    character::complete,
+    sequence::{preceded, tuple},
+};
+
+type Number<'a> = (Span<'a>, u32);
+
+/// A move as written, stack 0 included.
+fn raw_move(input: Span<'_>) -> ParseResult<'_, (Number<'_>, Number<'_>, Number<'_>)> {
+    tuple((
+        preceded(tag("move "), marked(complete::u32)),
+        preceded(tag(" from "), marked(complete::u32)),
+        preceded(tag(" to "), marked(complete::u32)),
+    ))(input)
+}
+
+/// Follow the moves on stack heights alone, flagging any that could
+/// not be carried out.
+pub fn lints(input: &str) -> Vec<Lint> {
+    let mut heights = match parse_crates(Span::new(input)) {
+        Ok((_, crates)) => crates.iter().map(Vec::len).collect::<Vec<_>>(),
+        Err(_) => return vec![],
+    };
+
+    // The drawing does not parse as moves, so only moves come back.
+    let (moves, _) = recover::lines(Span::new(input), raw_move);
+    let mut lints = vec![];
+
+    for (line, ((_, amount), from, to)) in moves {
+        let mut stacks = vec![];
+
+        for (span, stack) in [from, to] {
+            if stack == 0 {
+                lints.push(Lint::at(
+                    Severity::Error,
+                    "stack-zero",
+                    "stacks are numbered from 1".to_owned(),
+                    &span,
+                ));
+            } else if stack as usize > heights.len() {
+                lints.push(Lint::at(
+                    Severity::Error,
+                    "missing-stack",
+                    format!("there is no stack {}, only {}", stack, heights.len()),
+                    &span,
+                ));
+            } else {
+                stacks.push(stack as usize - 1);
+            }
+        }
+
+        let [from, to] = stacks[..] else {
+            continue;
+        };
+        let amount = amount as usize;
+
+        if amount > heights[from] {
+            lints.push(Lint::at(
+                Severity::Error,
+                "too-many-crates",
+                format!(
+                    "moves {} crates, but stack {} only holds {} by now",
+                    amount,
+                    from + 1,
+                    heights[from]
+                ),
+                &line,
+            ));
+        } else if from == to || amount == 0 {
+            lints.push(Lint::at(
+                Severity::Info,
+                "no-op-move",
+                "this move leaves every stack as it was".to_owned(),
+                &line,
+            ));
+        }
+
+        let moved = amount.min(heights[from]);
+        heights[from] -= moved;
+        heights[to] += moved;
+    }
+
+    for (i, _) in heights.iter().enumerate().filter(|(_, &h)| h == 0) {
+        lints.push(Lint {
+            severity: Severity::Error,
+            rule: "empty-stack",
+            message: format!("stack {} ends up empty, so it has no top crate", i + 1),
+            location: None,
+        });
+    }
+
+    lints
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn impossible_moves_are_flagged() {
+        let input = "[A]    \n[B] [C]\n 1   2 \n\nmove 1 from 0 to 2\nmove 3 from 1 to 2\nmove 1 from 2 to 3\n";
+
+        assert_eq!(
+            lints(input)
+                .iter()
+                .map(|l| (l.rule, l.location.as_ref().map(|l| (l.line, l.column))))
+                .collect::<Vec<_>>(),
+            vec![
+                ("stack-zero", Some((5, 13))),
+                ("too-many-crates", Some((6, 1))),
+                ("missing-stack", Some((7, 18))),
+                ("empty-stack", None),
+            ]
+        );
+    }
+}
@@ -0,0 +1,78 @@
+//! Deliberately naive reference solutions, for differential tests.
+
+use crate::{Input, Move};
+
+fn tops(crates: &[Vec<&str>]) -> Option<String> {
+    let mut tops = String::new();
+
+    for stack in crates {
+        tops.push_str(stack.last()?);
+    }
+
+    Some(tops)
+}
+
+fn possible(crates: &[Vec<&str>], m: &Move) -> bool {
+    m.from < crates.len() && m.to < crates.len() && crates[m.from].len() >= m.amount
+}
+
+/// Move crates one at a time.
+pub fn part1((crates, moves): &Input) -> Option<String> {
+    let mut crates = crates.clone();
+
+    for m in moves {
+        if !possible(&crates, m) {
+            return None;
+        }
+
+        for _ in 0..m.amount {
+            let krate = crates[m.from].pop()?;
+            crates[m.to].push(krate);
+        }
+    }
+
+    tops(&crates)
+}
+
+/// Move crates one at a time through a spare stack, which puts them
+/// back in their original order.
+pub fn part2((crates, moves): &Input) -> Option<String> {
+    let mut crates = crates.clone();
+
+    for m in moves {
+        if !possible(&crates, m) {
+            return None;
+        }
+
+        let mut spare = vec![];
+        for _ in 0..m.amount {
+            spare.push(crates[m.from].pop()?);
+        }
+        while let Some(krate) = spare.pop() {
+            crates[m.to].push(krate);
+        }
+    }
+
+    tops(&crates)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Day5;
+    use aoc_core::{oracle::differential, Answer, Part};
+
+    #[test]
+    fn part1_agrees() {
+        differential(&Day5, Part::One, |input: &Input| {
+            part1(input).map(Answer::from)
+        });
+    }
+
+    #[test]
+    fn part2_agrees() {
+        differential(&Day5, Part::Two, |input: &Input| {
+            part2(input).map(Answer::from)
+        });
+    }
+}
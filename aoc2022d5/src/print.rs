@@ -0,0 +1,82 @@
+use crate::{Input, Move};
+use std::fmt;
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "move {} from {} to {}",
+            self.amount,
+            self.from + 1,
+            self.to + 1
+        )
+    }
+}
+
+/// The crate drawing, stack numbers included. Empty cells are written
+/// as three spaces even at the end of a line, since the parser needs
+/// them to keep the columns apart.
+pub fn drawing(stacks: &[Vec<&str>]) -> String {
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+    let mut lines = vec![];
+
+    for level in (0..height).rev() {
+        let cells = stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(krate) => format!("[{}]", krate),
+                None => "   ".to_owned(),
+            })
+            .collect::<Vec<_>>();
+
+        lines.push(cells.join(" "));
+    }
+
+    let ids = (1..=stacks.len())
+        .map(|id| format!(" {} ", id))
+        .collect::<Vec<_>>();
+    lines.push(ids.join(" "));
+
+    lines.join("\n")
+}
+
+/// Print `input` back in the puzzle format.
+pub fn input((crates, moves): &Input) -> String {
+    let moves = moves.iter().map(|m| format!("{}\n", m)).collect::<String>();
+
+    format!("{}\n\n{}", drawing(crates), moves)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::gen::CRATES;
+    use crate::parse;
+    use proptest::prelude::*;
+
+    /// At least one crate, or there is no drawing to parse.
+    fn stacks() -> impl Strategy<Value = Vec<Vec<&'static str>>> {
+        let krate = prop::sample::select(CRATES.to_vec());
+
+        prop::collection::vec(prop::collection::vec(krate, 0..6), 1..=9)
+            .prop_filter("no crates", |stacks| stacks.iter().any(|s| !s.is_empty()))
+    }
+
+    fn moves() -> impl Strategy<Value = Vec<Move>> {
+        let one = (0..100usize, 0..9usize, 0..9usize).prop_map(|(amount, from, to)| Move {
+            amount,
+            from,
+            to,
+        });
+
+        prop::collection::vec(one, 1..20)
+    }
+
+    proptest! {
+        #[test]
+        fn parse_reverses_print(parsed in (stacks(), moves())) {
+            let text = input(&parsed);
+            prop_assert_eq!(parse(&text).unwrap(), parsed);
+        }
+    }
+}
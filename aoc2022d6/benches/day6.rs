@@ -0,0 +1,12 @@
+use aoc2022d6::Day6;
+use aoc_core::bench::criterion::{criterion_group, criterion_main, Criterion};
+
+fn day6(c: &mut Criterion) {
+    aoc_core::bench::solution(c, &Day6);
+
+    #[cfg(feature = "bytes")]
+    aoc_core::bench::bytes(c, &Day6, aoc2022d6::bytes::parse);
+}
+
+criterion_group!(benches, day6);
+criterion_main!(benches);
@@ -0,0 +1,20 @@
+use crate::Input;
+use aoc_core::AocError;
+use nom::{character::complete::alpha1, combinator::map_res, IResult};
+use std::str;
+
+fn datastream(input: &[u8]) -> IResult<&[u8], Input<'_>> {
+    map_res(alpha1, str::from_utf8)(input)
+}
+
+/// Byte level [`crate::parse`].
+pub fn parse(input: &[u8]) -> Result<Input<'_>, AocError> {
+    aoc_core::bytes::finish(input, datastream)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    aoc_core::bytes_agree!(crate::parse; "abc1\n", "\n");
+}
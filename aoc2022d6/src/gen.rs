@@ -0,0 +1,55 @@
+use aoc_core::gen::Rng;
+
+const LETTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+
+/// A datastream of `size` letters, at least 14, with a start-of-message
+/// marker guaranteed.
+///
+/// Before that marker at most 13 different letters are used, so no
+/// start-of-message marker shows up early. With four or more of them a
+/// start-of-packet marker usually does, well before it.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(14);
+    let before = rng.range(0..=size - 14);
+
+    let mut letters = LETTERS.to_vec();
+    rng.shuffle(&mut letters);
+    let alphabet = &letters[..rng.range(3..=13)];
+
+    let mut stream = (0..before).map(|_| *rng.pick(alphabet)).collect::<Vec<_>>();
+
+    rng.shuffle(&mut letters);
+    stream.extend(&letters[..14]);
+
+    stream.extend((stream.len()..size).map(|_| *rng.pick(LETTERS)));
+    stream.push(b'\n');
+
+    String::from_utf8(stream).unwrap()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{parse, part1, part2};
+
+    #[test]
+    fn generated_inputs_solve() {
+        for seed in 0..20 {
+            let input = input(&mut Rng::new(seed), 100);
+            let stream = parse(&input).unwrap();
+
+            assert_eq!(stream.len(), 100);
+            assert!(part1(stream).unwrap() <= part2(stream).unwrap());
+        }
+    }
+
+    #[test]
+    fn packets_start_before_messages() {
+        let apart = (0..20)
+            .map(|seed| input(&mut Rng::new(seed), 100))
+            .filter(|input| part2(input).unwrap() - part1(input).unwrap() > 20)
+            .count();
+
+        assert!(apart >= 5, "only {} inputs with markers apart", apart);
+    }
+}
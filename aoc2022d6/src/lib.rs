@@ -1,34 +1,97 @@
-use clap::Parser;
+#[cfg(feature = "bytes")]
+pub mod bytes;
+pub mod gen;
+pub mod lint;
+pub mod oracle;
+pub mod print;
+pub mod stream;
+
+use aoc_core::{
+    gen::Rng, input::Source, lint::Lint, shrink::Split, Answer, AocError, ParseResult, Part,
+    Solution, Span,
+};
+use nom::{character::complete::alpha1, combinator::map, error::context};
 use std::collections::BTreeSet;
+use std::io::BufRead;
 
-#[derive(Parser, Debug)]
-#[command(
-    name = "aoc2022d6",
-    author = "Felipe Balbi <felipe@balbi.sh>",
-    about = "Advent of Code 2022 Day 6",
-    version = "0.1.0"
-)]
-pub struct Cli {
-    #[arg(short = '1', long = "part1", help = "Run part 1.")]
-    part1: bool,
-
-    #[arg(short = '2', long = "part2", help = "Run part 2.")]
-    part2: bool,
-}
+/// The parsed puzzle input.
+pub type Input<'a> = &'a str;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input<'a> = Input<'a>;
+
+    fn name(&self) -> &'static str {
+        "aoc2022d6"
+    }
+
+    fn year(&self) -> u32 {
+        2022
+    }
+
+    fn day(&self) -> u32 {
+        6
+    }
 
-impl Cli {
-    pub fn run(&self, input: &str) {
-        if self.part1 {
-            println!("Part 1: {}", part1(input));
-        }
+    fn manifest_dir(&self) -> &'static str {
+        env!("CARGO_MANIFEST_DIR")
+    }
+
+    fn parse_span<'a>(&self, input: Span<'a>) -> ParseResult<'a, Self::Input<'a>> {
+        parse_datastream(input)
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        part2(input).map(Answer::from)
+    }
+
+    fn stream(
+        &self,
+        source: &Source,
+        reader: &mut dyn BufRead,
+        parts: &[Part],
+    ) -> Option<Result<Vec<(Part, Answer)>, AocError>> {
+        Some(stream::solve(source, reader, parts))
+    }
 
-        if self.part2 {
-            println!("Part 2: {}", part2(input));
-        }
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        Some(gen::input(rng, size))
     }
+
+    fn splits(&self, input: &str) -> Vec<Split> {
+        vec![Split {
+            pieces: input.trim_end().chars().map(String::from).collect(),
+            join: Box::new(|pieces| pieces.concat() + "\n"),
+        }]
+    }
+
+    fn print(&self, input: &Self::Input<'_>) -> Option<String> {
+        Some(print::input(input))
+    }
+
+    fn lint(&self, input: &str) -> Vec<Lint> {
+        lint::lints(input)
+    }
+}
+
+/// Check the datastream is all letters; it is its own [`Input`].
+pub fn parse(input: &str) -> Result<Input<'_>, AocError> {
+    Day6.parse(input)
 }
 
-fn find_packet_of_length(input: &str, length: usize) -> usize {
+fn parse_datastream<'a>(input: Span<'a>) -> ParseResult<'a, &'a str> {
+    context(
+        "a datastream of letters",
+        map(alpha1, |stream: Span<'a>| *stream.fragment()),
+    )(input)
+}
+
+fn find_packet_of_length(input: &str, length: usize) -> Result<usize, AocError> {
     input
         .chars()
         .collect::<Vec<_>>()
@@ -41,14 +104,14 @@ fn find_packet_of_length(input: &str, length: usize) -> usize {
         .collect::<Vec<_>>()
         .first()
         .map(|(i, _)| i + length)
-        .unwrap_or(0)
+        .ok_or_else(|| AocError::Validation(format!("no {} distinct characters in a row", length)))
 }
 
-fn part1(input: &str) -> usize {
+pub fn part1(input: &str) -> Result<usize, AocError> {
     find_packet_of_length(input, 4)
 }
 
-fn part2(input: &str) -> usize {
+pub fn part2(input: &str) -> Result<usize, AocError> {
     find_packet_of_length(input, 14)
 }
 
@@ -56,36 +119,20 @@ fn part2(input: &str) -> usize {
 mod test {
     use super::*;
 
-    #[test]
-    fn part1_works() {
-        let input = "bvwbjplbgvbhsrlpgdmjqwftvncz";
-        assert_eq!(part1(input), 5);
-
-        let input = "nppdvjthqldpwncqszvftbrmjlhg";
-        assert_eq!(part1(input), 6);
-
-        let input = "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg";
-        assert_eq!(part1(input), 10);
+    aoc_core::verify_answers!(Day6);
+    aoc_core::verify_examples!(Day6);
 
-        let input = "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw";
-        assert_eq!(part1(input), 11);
+    #[test]
+    fn markers_need_not_be_letters() {
+        assert_eq!(part1("1121234").unwrap(), 7);
+        assert_eq!(part2("0123456789abcd").unwrap(), 14);
     }
 
     #[test]
-    fn part2_works() {
-        let input = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
-        assert_eq!(part2(input), 19);
-
-        let input = "bvwbjplbgvbhsrlpgdmjqwftvncz";
-        assert_eq!(part2(input), 23);
-
-        let input = "nppdvjthqldpwncqszvftbrmjlhg";
-        assert_eq!(part2(input), 23);
-
-        let input = "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg";
-        assert_eq!(part2(input), 29);
+    fn splits_are_characters() {
+        let splits = Day6.splits("abc\n");
 
-        let input = "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw";
-        assert_eq!(part2(input), 26);
+        assert_eq!(splits[0].pieces, ["a", "b", "c"]);
+        assert_eq!((splits[0].join)(&splits[0].pieces[1..]), "bc\n");
     }
 }
@@ -0,0 +1,43 @@
+use crate::{find_packet_of_length, parse_datastream};
+use aoc_core::lint::{Lint, Severity};
+use aoc_core::{recover, Span};
+
+pub fn lints(input: &str) -> Vec<Lint> {
+    let (streams, _) = recover::lines(Span::new(input), parse_datastream);
+    let mut lints = vec![];
+
+    for (span, stream) in &streams {
+        for (rule, length, part) in [("no-packet-marker", 4, 1), ("no-message-marker", 14, 2)] {
+            if find_packet_of_length(stream, length).is_err() {
+                lints.push(Lint::at(
+                    Severity::Error,
+                    rule,
+                    format!(
+                        "no {} different letters in a row, so part {} has no answer",
+                        length, part
+                    ),
+                    span,
+                ));
+            }
+        }
+    }
+
+    lints
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn missing_markers_are_flagged() {
+        let rules = |input| lints(input).iter().map(|l| l.rule).collect::<Vec<_>>();
+
+        assert_eq!(
+            rules("aabbccdd\n"),
+            ["no-packet-marker", "no-message-marker"]
+        );
+        assert_eq!(rules("abcdabcd\n"), ["no-message-marker"]);
+        assert!(rules("mjqjpqmgbljsphdztnvjfqwrcgsmlb\n").is_empty());
+    }
+}
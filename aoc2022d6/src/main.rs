@@ -1,8 +1,5 @@
-use aoc2022d6::Cli;
-use clap::Parser;
-use std::fs;
+use aoc2022d6::Day6;
 
 fn main() {
-    let input = fs::read_to_string("input.txt").unwrap();
-    Cli::parse().run(&input);
+    aoc_core::main(&Day6);
 }
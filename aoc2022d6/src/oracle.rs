@@ -0,0 +1,40 @@
+//! Deliberately naive reference solutions, for differential tests.
+
+/// Compare every pair of letters in every window.
+fn marker(stream: &str, length: usize) -> Option<usize> {
+    let letters = stream.as_bytes();
+
+    (length..=letters.len()).find(|&end| {
+        let window = &letters[end - length..end];
+        (0..length).all(|i| (0..length).all(|j| i == j || window[i] != window[j]))
+    })
+}
+
+pub fn part1(stream: &str) -> Option<usize> {
+    marker(stream, 4)
+}
+
+pub fn part2(stream: &str) -> Option<usize> {
+    marker(stream, 14)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Day6, Input};
+    use aoc_core::{oracle::differential, Answer, Part};
+
+    #[test]
+    fn part1_agrees() {
+        differential(&Day6, Part::One, |input: &Input| {
+            part1(input).map(Answer::from)
+        });
+    }
+
+    #[test]
+    fn part2_agrees() {
+        differential(&Day6, Part::Two, |input: &Input| {
+            part2(input).map(Answer::from)
+        });
+    }
+}
@@ -0,0 +1,19 @@
+/// Print a datastream back in the puzzle format.
+pub fn input(stream: &str) -> String {
+    format!("{}\n", stream)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parse;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn parse_reverses_print(stream in "[a-zA-Z]{1,100}") {
+            let text = input(&stream);
+            prop_assert_eq!(parse(&text).unwrap(), stream.as_str());
+        }
+    }
+}
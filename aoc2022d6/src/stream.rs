@@ -0,0 +1,171 @@
+use aoc_core::{input::Source, stream::Stream, Answer, AocError, Diagnostic, Part};
+use nom::{
+    combinator::verify,
+    error::{Error, ErrorKind},
+    number::streaming::u8,
+    IResult, Needed,
+};
+use std::collections::VecDeque;
+use std::io::BufRead;
+
+fn letter(input: &[u8]) -> IResult<&[u8], u8> {
+    // A line ending closes the datastream. Only whitespace may follow it,
+    // and the stream skips that once it has seen the end of the input.
+    if input == b"\r" {
+        return Err(nom::Err::Incomplete(Needed::Unknown));
+    }
+    if let Some(rest) = input
+        .strip_prefix(b"\r\n")
+        .or_else(|| input.strip_prefix(b"\n"))
+    {
+        return match rest.iter().position(|b| !b.is_ascii_whitespace()) {
+            Some(i) => Err(nom::Err::Error(Error::new(&rest[i..], ErrorKind::Eof))),
+            None => Err(nom::Err::Incomplete(Needed::Unknown)),
+        };
+    }
+
+    verify(u8, u8::is_ascii_alphabetic)(input)
+}
+
+/// Every letter of the datastream in `reader`, one at a time.
+pub fn letters<R: BufRead>(
+    source: &Source,
+    reader: R,
+) -> impl Iterator<Item = Result<u8, AocError>> {
+    Stream::new(source, reader, letter)
+}
+
+/// Looks for the first `length` letters in a row that all differ,
+/// remembering no more than `length` of them.
+struct Marker {
+    length: usize,
+    window: VecDeque<u8>,
+    end: Option<usize>,
+}
+
+impl Marker {
+    fn new(length: usize) -> Self {
+        Self {
+            length,
+            window: VecDeque::with_capacity(length + 1),
+            end: None,
+        }
+    }
+
+    fn push(&mut self, position: usize, letter: u8) {
+        if self.end.is_some() {
+            return;
+        }
+
+        self.window.push_back(letter);
+        if self.window.len() > self.length {
+            self.window.pop_front();
+        }
+
+        let distinct = self
+            .window
+            .iter()
+            .enumerate()
+            .all(|(i, a)| self.window.iter().skip(i + 1).all(|b| a != b));
+
+        if self.window.len() == self.length && distinct {
+            self.end = Some(position + 1);
+        }
+    }
+
+    fn answer(&self) -> Result<Answer, AocError> {
+        self.end.map(Answer::from).ok_or_else(|| {
+            AocError::Validation(format!("no {} distinct characters in a row", self.length))
+        })
+    }
+}
+
+/// Solve `parts` in one pass, stopping as soon as every marker asked
+/// for has been found.
+pub fn solve<R: BufRead>(
+    source: &Source,
+    reader: R,
+    parts: &[Part],
+) -> Result<Vec<(Part, Answer)>, AocError> {
+    let mut markers = parts
+        .iter()
+        .map(|part| match part {
+            Part::One => Marker::new(4),
+            Part::Two => Marker::new(14),
+        })
+        .collect::<Vec<_>>();
+
+    let mut seen = 0;
+    for (position, letter) in letters(source, reader).enumerate() {
+        let letter = letter?;
+        seen += 1;
+
+        for marker in &mut markers {
+            marker.push(position, letter);
+        }
+
+        if markers.iter().all(|marker| marker.end.is_some()) {
+            break;
+        }
+    }
+
+    if seen == 0 {
+        return Err(AocError::Parse(Diagnostic {
+            message: "expected a datastream of letters".to_owned(),
+            location: None,
+        }));
+    }
+
+    parts
+        .iter()
+        .zip(&markers)
+        .map(|(&part, marker)| Ok((part, marker.answer()?)))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Day6;
+    use aoc_core::Solution;
+    use std::io::BufReader;
+
+    #[test]
+    fn solve_works() {
+        let reader = BufReader::with_capacity(3, "mjqjpqmgbljsphdztnvjfqwrcgsmlb".as_bytes());
+
+        assert_eq!(
+            solve(&Source::Stdin, reader, &[Part::One, Part::Two]).unwrap(),
+            vec![(Part::One, 7usize.into()), (Part::Two, 19usize.into())]
+        );
+
+        let reader = BufReader::with_capacity(3, "abcabc\n".as_bytes());
+
+        assert!(solve(&Source::Stdin, reader, &[Part::One]).is_err());
+    }
+
+    #[test]
+    fn fails_like_in_memory() {
+        for input in ["abcabc\n", "abcabc\r\n\n", "abc\nabcd\n", "abc1\n", ""] {
+            let reader = BufReader::with_capacity(3, input.as_bytes());
+            let streamed = solve(&Source::Stdin, reader, &[Part::One]).map_err(|e| e.kind());
+            let in_memory = Day6
+                .parse(input)
+                .and_then(|parsed| Day6.part1(&parsed))
+                .map_err(|e| e.kind());
+
+            assert_eq!(
+                streamed.map(|answers| answers[0].1.clone()),
+                in_memory,
+                "{:?}",
+                input
+            );
+        }
+
+        let reader = BufReader::with_capacity(3, "abcd\r\n".as_bytes());
+        assert_eq!(
+            solve(&Source::Stdin, reader, &[Part::One]).unwrap(),
+            vec![(Part::One, 4usize.into())]
+        );
+    }
+}
@@ -0,0 +1,12 @@
+use aoc2022d7::Day7;
+use aoc_core::bench::criterion::{criterion_group, criterion_main, Criterion};
+
+fn day7(c: &mut Criterion) {
+    aoc_core::bench::solution(c, &Day7);
+
+    #[cfg(feature = "bytes")]
+    aoc_core::bench::bytes(c, &Day7, aoc2022d7::bytes::parse);
+}
+
+criterion_group!(benches, day7);
+criterion_main!(benches);
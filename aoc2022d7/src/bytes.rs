@@ -0,0 +1,73 @@
+use crate::{Cd, Command, Inode, Input};
+use aoc_core::AocError;
+use nom::{
+    branch::alt,
+    bytes::complete::{is_a, tag},
+    character::complete::{self, alpha1, line_ending, not_line_ending, space1},
+    combinator::{map, map_res},
+    multi::separated_list1,
+    sequence::{preceded, separated_pair, terminated},
+    IResult,
+};
+use std::str;
+
+fn file(input: &[u8]) -> IResult<&[u8], Inode<'_>> {
+    map(
+        separated_pair(
+            complete::u32,
+            space1,
+            map_res(is_a("abcdefghijklmnopqrstuvwxyz."), str::from_utf8),
+        ),
+        |(size, name)| Inode::File { size, name },
+    )(input)
+}
+
+fn directory(input: &[u8]) -> IResult<&[u8], Inode<'_>> {
+    map(
+        preceded(
+            terminated(tag("dir"), space1),
+            map_res(alpha1, str::from_utf8),
+        ),
+        Inode::Dir,
+    )(input)
+}
+
+fn ls(input: &[u8]) -> IResult<&[u8], Command<'_>> {
+    map(
+        preceded(
+            terminated(tag("$ ls"), line_ending),
+            separated_list1(line_ending, alt((file, directory))),
+        ),
+        Command::Ls,
+    )(input)
+}
+
+fn cd(input: &[u8]) -> IResult<&[u8], Command<'_>> {
+    map(
+        preceded(
+            terminated(tag("$ cd"), space1),
+            map_res(not_line_ending, str::from_utf8),
+        ),
+        |name| match name {
+            "/" => Command::Cd(Cd::Root),
+            ".." => Command::Cd(Cd::Up),
+            name => Command::Cd(Cd::Down(name)),
+        },
+    )(input)
+}
+
+fn commands(input: &[u8]) -> IResult<&[u8], Input<'_>> {
+    separated_list1(line_ending, alt((ls, cd)))(input)
+}
+
+/// Byte level [`crate::parse`].
+pub fn parse(input: &[u8]) -> Result<Input<'_>, AocError> {
+    aoc_core::bytes::finish(input, commands)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    aoc_core::bytes_agree!(crate::parse; "$ cd\n", "$ ls\nfoo bar\n");
+}
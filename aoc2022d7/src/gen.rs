@@ -0,0 +1,146 @@
+use aoc_core::gen::Rng;
+
+const EXTENSIONS: [&str; 4] = ["txt", "dat", "log", "lst"];
+
+struct Dir {
+    name: String,
+    files: Vec<(usize, String)>,
+    dirs: Vec<usize>,
+}
+
+/// A name made of lowercase letters, unique for every `n`.
+fn name(mut n: usize) -> String {
+    let mut name = vec![];
+
+    loop {
+        name.push(b'a' + (n % 26) as u8);
+        n /= 26;
+        if n == 0 {
+            break;
+        }
+        n -= 1;
+    }
+
+    String::from_utf8(name).unwrap()
+}
+
+fn listing(rng: &mut Rng, dirs: &[Dir], dir: usize, lines: &mut Vec<String>) {
+    let mut entries = dirs[dir]
+        .dirs
+        .iter()
+        .map(|&child| format!("dir {}", dirs[child].name))
+        .chain(
+            dirs[dir]
+                .files
+                .iter()
+                .map(|(size, name)| format!("{} {}", size, name)),
+        )
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut entries);
+
+    lines.push("$ ls".to_owned());
+    lines.extend(entries);
+}
+
+/// `path` leads from the root down to `dir`.
+fn transcript(
+    rng: &mut Rng,
+    dirs: &[Dir],
+    path: &mut Vec<usize>,
+    dir: usize,
+    lines: &mut Vec<String>,
+) {
+    listing(rng, dirs, dir, lines);
+
+    for &child in &dirs[dir].dirs {
+        lines.push(format!("$ cd {}", dirs[child].name));
+        path.push(child);
+        transcript(rng, dirs, path, child, lines);
+        path.pop();
+        lines.push("$ cd ..".to_owned());
+
+        if rng.one_in(8) {
+            lines.push("$ cd /".to_owned());
+            lines.extend(path.iter().map(|&dir| format!("$ cd {}", dirs[dir].name)));
+        }
+    }
+
+    if rng.one_in(8) {
+        listing(rng, dirs, dir, lines);
+    }
+}
+
+/// A transcript exploring a random tree of `size` directories. Every
+/// directory is listed before it is entered, sometimes again on the way
+/// out, and holds at least one entry. Now and then the transcript goes
+/// back to `/` and walks down again. The disk never holds more than it
+/// can.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let largest = (69_000_000 / (4 * size)).clamp(1, 300_000);
+
+    let mut dirs = (0..size)
+        .map(|i| Dir {
+            name: name(i),
+            files: vec![],
+            dirs: vec![],
+        })
+        .collect::<Vec<_>>();
+
+    for i in 1..size {
+        let parent = rng.range(0..=i - 1);
+        dirs[parent].dirs.push(i);
+    }
+
+    for dir in &mut dirs {
+        let count = rng.range(0..=3) + usize::from(dir.dirs.is_empty());
+
+        dir.files = (0..count)
+            .map(|n| {
+                let mut file = name(n);
+                if rng.one_in(2) {
+                    file.push('.');
+                    file.push_str(EXTENSIONS[rng.range(0..=EXTENSIONS.len() - 1)]);
+                }
+                (rng.range(1..=largest), file)
+            })
+            .collect();
+    }
+
+    let mut lines = vec!["$ cd /".to_owned()];
+    transcript(rng, &dirs, &mut vec![], 0, &mut lines);
+
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{parse, part1, part2, Command};
+
+    #[test]
+    fn generated_inputs_revisit() {
+        let input = (0..20)
+            .map(|seed| input(&mut Rng::new(seed), 40))
+            .collect::<String>();
+
+        assert!(input.matches("$ cd /\n").count() > 20);
+        assert!(input.matches("$ ls\n").count() > 20 * 40);
+    }
+
+    #[test]
+    fn generated_inputs_solve() {
+        for seed in 0..20 {
+            let input = input(&mut Rng::new(seed), 40);
+            let commands = parse(&input).unwrap();
+            let listings = commands
+                .iter()
+                .filter(|c| matches!(c, Command::Ls(_)))
+                .count();
+
+            assert!(listings >= 40);
+            part1(&commands);
+            part2(&commands).unwrap();
+        }
+    }
+}
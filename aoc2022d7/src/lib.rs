@@ -1,115 +1,225 @@
-use std::collections::BTreeMap;
-
-use clap::Parser;
+#[cfg(feature = "bytes")]
+pub mod bytes;
+pub mod gen;
+pub mod lint;
+pub mod oracle;
+pub mod print;
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use aoc_core::{
+    gen::Rng,
+    lint::Lint,
+    recover::{self, Report},
+    shrink::Split,
+    Answer, AocError, Diagnostic, Location, ParseResult, Solution, Span,
+};
 use nom::{
     branch::alt,
     bytes::complete::{is_a, tag},
     character::complete::{self, alpha1, line_ending, not_line_ending, space1},
     combinator::map,
+    error::context,
     multi::separated_list1,
     sequence::{separated_pair, terminated, tuple},
-    IResult,
 };
+use serde::Serialize;
 
-#[derive(Parser, Debug)]
-#[command(
-    name = "aoc2022d7",
-    author = "Felipe Balbi <felipe@balbi.sh>",
-    about = "Advent of Code 2022 Day 7",
-    version = "0.1.0"
-)]
-pub struct Cli {
-    #[arg(short = '1', long = "part1", help = "Run part 1.")]
-    part1: bool,
-
-    #[arg(short = '2', long = "part2", help = "Run part 2.")]
-    part2: bool,
-}
-
-impl Cli {
-    pub fn run(&self, input: &str) {
-        if self.part1 {
-            println!("Part 1: {}", part1(input));
-        }
+/// The parsed puzzle input.
+pub type Input<'a> = Vec<Command<'a>>;
 
-        if self.part2 {
-            println!("Part 2: {}", part2(input));
-        }
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input<'a> = Input<'a>;
+
+    fn name(&self) -> &'static str {
+        "aoc2022d7"
+    }
+
+    fn year(&self) -> u32 {
+        2022
+    }
+
+    fn day(&self) -> u32 {
+        7
+    }
+
+    fn manifest_dir(&self) -> &'static str {
+        env!("CARGO_MANIFEST_DIR")
+    }
+
+    fn parse_span<'a>(&self, input: Span<'a>) -> ParseResult<'a, Self::Input<'a>> {
+        parse_input(input)
+    }
+
+    fn parse_recovering<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, AocError> {
+        let (lines, mut report) = recover::lines(Span::new(input), line);
+        let cmds = assemble(lines, &mut report);
+
+        report.finish(cmds)
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        part2(input).map(Answer::from)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        Some(gen::input(rng, size))
+    }
+
+    fn splits(&self, input: &str) -> Vec<Split> {
+        vec![
+            Split::blocks(input, |line| line.starts_with('$')),
+            Split::lines(input),
+        ]
+    }
+
+    fn print(&self, input: &Self::Input<'_>) -> Option<String> {
+        Some(print::input(input))
+    }
+
+    fn lint(&self, input: &str) -> Vec<Lint> {
+        lint::lints(input)
     }
 }
 
-#[derive(Debug)]
-enum Cd<'a> {
+/// Parse the terminal session into commands, each `ls` carrying its
+/// listing.
+pub fn parse(input: &str) -> Result<Input<'_>, AocError> {
+    Day7.parse(input)
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum Cd<'a> {
     Root,
     Up,
     Down(&'a str),
 }
 
-#[derive(Debug)]
-enum Command<'a> {
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum Command<'a> {
     Cd(Cd<'a>),
     Ls(Vec<Inode<'a>>),
 }
 
-#[derive(Debug)]
-enum Inode<'a> {
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum Inode<'a> {
     Dir(&'a str),
-    File { size: u32 },
+    File { size: u32, name: &'a str },
 }
 
-fn file(input: &str) -> IResult<&str, Inode> {
-    map(
-        separated_pair(complete::u32, space1, is_a("abcdefghijklmnopqrstuvwxyz.")),
-        |(size, _)| Inode::File { size },
+fn file<'a>(input: Span<'a>) -> ParseResult<'a, Inode<'a>> {
+    context(
+        "`<size> <name>`",
+        map(
+            separated_pair(complete::u32, space1, is_a("abcdefghijklmnopqrstuvwxyz.")),
+            |(size, name): (_, Span<'a>)| Inode::File {
+                size,
+                name: name.fragment(),
+            },
+        ),
     )(input)
 }
 
-fn directory(input: &str) -> IResult<&str, Inode> {
-    map(separated_pair(tag("dir"), space1, alpha1), |(_, name)| {
-        Inode::Dir(name)
-    })(input)
+fn directory<'a>(input: Span<'a>) -> ParseResult<'a, Inode<'a>> {
+    context(
+        "`dir <name>`",
+        map(
+            separated_pair(tag("dir"), space1, alpha1),
+            |(_, name): (_, Span<'a>)| Inode::Dir(name.fragment()),
+        ),
+    )(input)
 }
 
-fn inodes(input: &str) -> IResult<&str, Vec<Inode>> {
+fn inodes<'a>(input: Span<'a>) -> ParseResult<'a, Vec<Inode<'a>>> {
     separated_list1(line_ending, alt((file, directory)))(input)
 }
 
-fn ls(input: &str) -> IResult<&str, Command> {
+fn ls<'a>(input: Span<'a>) -> ParseResult<'a, Command<'a>> {
     map(
         tuple((terminated(tag("$ ls"), line_ending), inodes)),
         |(_, inodes)| Command::Ls(inodes),
     )(input)
 }
 
-fn cd(input: &str) -> IResult<&str, Command> {
+fn cd<'a>(input: Span<'a>) -> ParseResult<'a, Command<'a>> {
     map(
         separated_pair(tag("$ cd"), space1, not_line_ending),
-        |(_, name)| match name {
+        |(_, name): (_, Span<'a>)| match *name.fragment() {
             "/" => Command::Cd(Cd::Root),
             ".." => Command::Cd(Cd::Up),
-            _ => Command::Cd(Cd::Down(name)),
+            name => Command::Cd(Cd::Down(name)),
         },
     )(input)
 }
 
-fn commands(input: &str) -> IResult<&str, Vec<Command>> {
-    separated_list1(line_ending, alt((ls, cd)))(input)
+fn commands<'a>(input: Span<'a>) -> ParseResult<'a, Vec<Command<'a>>> {
+    separated_list1(
+        line_ending,
+        context("`$ cd <dir>` or `$ ls`", alt((ls, cd))),
+    )(input)
 }
 
-fn parse_input_part1(input: &str) -> IResult<&str, Vec<Command>> {
-    commands(input)
+/// A single transcript line, for recovery mode.
+enum Line<'a> {
+    Command(Command<'a>),
+    Entry(Inode<'a>),
+}
+
+fn line<'a>(input: Span<'a>) -> ParseResult<'a, Line<'a>> {
+    context(
+        "`$ cd <dir>`, `$ ls` or a directory entry",
+        alt((
+            map(alt((file, directory)), Line::Entry),
+            map(tag("$ ls"), |_| Line::Command(Command::Ls(vec![]))),
+            map(cd, Line::Command),
+        )),
+    )(input)
+}
+
+/// Put `ls` output back under its `ls`. Entries with no `ls` before
+/// them are reported rather than dropped.
+fn assemble<'a>(lines: Vec<(Span<'a>, Line<'a>)>, report: &mut Report) -> Vec<Command<'a>> {
+    let mut cmds = vec![];
+
+    for (span, line) in lines {
+        match (line, cmds.last_mut()) {
+            (Line::Entry(inode), Some(Command::Ls(inodes))) => inodes.push(inode),
+            (Line::Entry(_), _) => {
+                report.errors.push(Diagnostic {
+                    message: "directory entry outside of an `ls` listing".to_owned(),
+                    location: Some(Location::of(&span)),
+                });
+                report.parsed -= 1;
+            }
+            (Line::Command(cmd), _) => cmds.push(cmd),
+        }
+    }
+
+    cmds
 }
 
-fn parse_input_part2(input: &str) -> IResult<&str, Vec<Command>> {
-    parse_input_part1(input)
+fn parse_input<'a>(input: Span<'a>) -> ParseResult<'a, Vec<Command<'a>>> {
+    commands(input)
 }
 
-fn fold_sizes<'a>(
-    (mut stack, mut table): (Vec<&'a str>, BTreeMap<Vec<&'a str>, u32>),
-    cmd: &'a Command,
-) -> (Vec<&'a str>, BTreeMap<Vec<&'a str>, u32>) {
+/// The working directory, the directories listed so far and the size
+/// of each.
+type Sizes<'a> = (
+    Vec<&'a str>,
+    BTreeSet<Vec<&'a str>>,
+    BTreeMap<Vec<&'a str>, u64>,
+);
+
+fn fold_sizes<'a>((mut stack, mut listed, mut table): Sizes<'a>, cmd: &'a Command) -> Sizes<'a> {
     match cmd {
         Command::Cd(Cd::Root) => {
+            stack.clear();
             stack.push("");
         }
         Command::Cd(Cd::Up) => {
@@ -118,17 +228,19 @@ fn fold_sizes<'a>(
         Command::Cd(Cd::Down(name)) => {
             stack.push(name);
         }
+        // A directory listed again holds nothing it did not before.
+        Command::Ls(_) if !listed.insert(stack.clone()) => {}
         Command::Ls(inodes) => {
             let size = inodes
                 .iter()
                 .filter_map(|inode| {
                     if let Inode::File { size, .. } = inode {
-                        Some(size)
+                        Some(u64::from(*size))
                     } else {
                         None
                     }
                 })
-                .sum::<u32>();
+                .sum::<u64>();
 
             for i in 0..stack.len() {
                 table
@@ -138,108 +250,108 @@ fn fold_sizes<'a>(
             }
         }
     };
-    (stack, table)
+    (stack, listed, table)
 }
 
-fn part1(input: &str) -> u32 {
-    let (_, cmds) = parse_input_part1(input).unwrap();
-
-    let (_, table) = cmds
-        .iter()
-        .fold((Vec::default(), BTreeMap::default()), fold_sizes);
+pub fn part1(cmds: &[Command]) -> u64 {
+    let (_, _, table) = cmds.iter().fold(Sizes::default(), fold_sizes);
 
     table
         .iter()
-        .filter(|(_, &size)| size < 100_000)
+        .filter(|(_, &size)| size <= 100_000)
         .map(|(_, size)| size)
-        .sum::<u32>()
+        .sum::<u64>()
 }
 
-fn part2(input: &str) -> u32 {
-    let (_, cmds) = parse_input_part2(input).unwrap();
-
-    let (_, table) = cmds
-        .iter()
-        .fold((Vec::default(), BTreeMap::default()), fold_sizes);
-
-    let total_space = 70_000_000;
-    let needed_space = 30_000_000;
-    let used_space = table.first_key_value().unwrap().1;
-    let free_space = total_space - used_space;
-    let space_to_free = needed_space - free_space;
+pub fn part2(cmds: &[Command]) -> Result<u64, AocError> {
+    let (_, _, table) = cmds.iter().fold(Sizes::default(), fold_sizes);
+
+    let total_space: u64 = 70_000_000;
+    let needed_space: u64 = 30_000_000;
+    let used_space = table
+        .first_key_value()
+        .map(|(_, size)| *size)
+        .ok_or_else(|| AocError::Validation("transcript lists no directories".to_owned()))?;
+    let free_space = total_space.checked_sub(used_space).ok_or_else(|| {
+        AocError::Validation(format!(
+            "{} bytes used on a {} byte disk",
+            used_space, total_space
+        ))
+    })?;
+    let space_to_free = needed_space.saturating_sub(free_space);
 
     let mut sizes = table
         .into_iter()
         .filter(|(_, size)| size >= &space_to_free)
         .map(|(_, size)| size)
-        .collect::<Vec<u32>>();
+        .collect::<Vec<u64>>();
 
     sizes.sort();
 
-    *sizes.first().unwrap()
+    Ok(sizes[0])
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
 
+    aoc_core::verify_answers!(Day7);
+    aoc_core::verify_examples!(Day7);
+
+    #[test]
+    fn nested_sizes_count_towards_root() {
+        let commands = vec![
+            Command::Cd(Cd::Root),
+            Command::Ls(vec![
+                Inode::File {
+                    size: 40_000_000,
+                    name: "b.txt",
+                },
+                Inode::Dir("a"),
+            ]),
+            Command::Cd(Cd::Down("a")),
+            Command::Ls(vec![Inode::File {
+                size: 2_000,
+                name: "c",
+            }]),
+        ];
+
+        assert_eq!(part1(&commands), 2_000);
+        assert_eq!(part2(&commands).unwrap(), 2_000);
+    }
+
+    #[test]
+    fn directory_of_exactly_the_limit_counts() {
+        let input = "$ cd /\n$ ls\ndir a\n$ cd a\n$ ls\n100000 b\n";
+        let commands = Day7.parse(input).unwrap();
+
+        assert_eq!(part1(&commands), 200_000);
+    }
+
+    #[test]
+    fn sizes_do_not_overflow() {
+        let input = "$ cd /\n$ ls\n4294967295 a\n1 b\n";
+        let commands = Day7.parse(input).unwrap();
+
+        assert_eq!(part1(&commands), 0);
+        assert!(matches!(part2(&commands), Err(AocError::Validation(_))));
+    }
+
     #[test]
-    fn part1_works() {
-        let input = "$ cd /
-$ ls
-dir a
-14848514 b.txt
-8504156 c.dat
-dir d
-$ cd a
-$ ls
-dir e
-29116 f
-2557 g
-62596 h.lst
-$ cd e
-$ ls
-584 i
-$ cd ..
-$ cd ..
-$ cd d
-$ ls
-4060174 j
-8033020 d.log
-5626152 d.ext
-7214296 k
-";
-
-        assert_eq!(part1(input), 95437);
+    fn directories_are_counted_once() {
+        let input =
+            "$ cd /\n$ ls\ndir a\n60000 r\n$ cd a\n$ ls\n5 x\n$ cd /\n$ ls\ndir a\n60000 r\n";
+        let commands = Day7.parse(input).unwrap();
+
+        assert_eq!(part1(&commands), 60_010);
+        assert_eq!(part1(&commands), oracle::part1(&commands));
     }
 
     #[test]
-    fn part2_works() {
-        let input = "$ cd /
-$ ls
-dir a
-14848514 b.txt
-8504156 c.dat
-dir d
-$ cd a
-$ ls
-dir e
-29116 f
-2557 g
-62596 h.lst
-$ cd e
-$ ls
-584 i
-$ cd ..
-$ cd ..
-$ cd d
-$ ls
-4060174 j
-8033020 d.log
-5626152 d.ext
-7214296 k
-";
-
-        assert_eq!(part2(input), 24933642);
+    fn commands_split_before_lines() {
+        let splits = Day7.splits("$ cd /\n$ ls\n1 a\ndir b\n");
+
+        assert_eq!(splits[0].pieces, ["$ cd /", "$ ls\n1 a\ndir b"]);
+        assert_eq!(splits[1].pieces.len(), 4);
     }
 }
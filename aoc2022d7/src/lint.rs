@@ -0,0 +1,97 @@
+use crate::{line, Cd, Command, Inode, Line};
+use aoc_core::lint::{Lint, Severity};
+use aoc_core::{recover, Span};
+use std::collections::BTreeMap;
+
+pub fn lints(input: &str) -> Vec<Lint> {
+    let (lines, _) = recover::lines(Span::new(input), line);
+    let mut lints = vec![];
+
+    let mut cwd: Vec<&str> = vec![];
+    // Where each directory was listed, and the directories it holds.
+    let mut listings: BTreeMap<Vec<&str>, (u32, Vec<&str>)> = BTreeMap::new();
+    let mut listing = None;
+
+    for (span, line) in lines {
+        match line {
+            Line::Command(Command::Cd(Cd::Root)) => cwd.clear(),
+            Line::Command(Command::Cd(Cd::Up)) => {
+                if cwd.pop().is_none() {
+                    lints.push(Lint::at(
+                        Severity::Warning,
+                        "above-root",
+                        "already at the root, `cd ..` goes nowhere".to_owned(),
+                        &span,
+                    ));
+                }
+            }
+            Line::Command(Command::Cd(Cd::Down(name))) => {
+                let listed = listings
+                    .get(&cwd)
+                    .is_some_and(|(_, dirs)| dirs.contains(&name));
+
+                if !listed {
+                    lints.push(Lint::at(
+                        Severity::Warning,
+                        "unlisted-dir",
+                        format!("`{}` was not listed in /{}", name, cwd.join("/")),
+                        &span,
+                    ));
+                }
+
+                cwd.push(name);
+            }
+            Line::Command(Command::Ls(_)) => {
+                if let Some((first, _)) = listings.get(&cwd) {
+                    lints.push(Lint::at(
+                        Severity::Info,
+                        "relisted-dir",
+                        format!(
+                            "/{} was already listed on line {}, its files still count once",
+                            cwd.join("/"),
+                            first
+                        ),
+                        &span,
+                    ));
+                }
+
+                listings.insert(cwd.clone(), (span.location_line(), vec![]));
+                listing = Some(cwd.clone());
+                continue;
+            }
+            Line::Entry(Inode::Dir(name)) => {
+                if let Some((_, dirs)) = listing.as_ref().and_then(|dir| listings.get_mut(dir)) {
+                    dirs.push(name);
+                }
+                continue;
+            }
+            Line::Entry(Inode::File { .. }) => continue,
+        }
+
+        listing = None;
+    }
+
+    lints
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn suspicious_navigation_is_flagged() {
+        let input = "$ cd /\n$ ls\ndir a\n$ cd b\n$ cd ..\n$ cd ..\n$ ls\n1 c\n";
+
+        assert_eq!(
+            lints(input)
+                .iter()
+                .map(|l| (l.severity, l.rule, l.location.as_ref().unwrap().line))
+                .collect::<Vec<_>>(),
+            vec![
+                (Severity::Warning, "unlisted-dir", 4),
+                (Severity::Warning, "above-root", 6),
+                (Severity::Info, "relisted-dir", 7)
+            ]
+        );
+    }
+}
@@ -0,0 +1,85 @@
+//! Deliberately naive reference solutions, for differential tests.
+
+use crate::{Cd, Command, Inode};
+use std::collections::{BTreeMap, BTreeSet};
+
+type Path<'a> = Vec<&'a str>;
+
+/// Every listed directory and every file, keyed by its directory and
+/// name so a file listed twice is only counted once.
+fn walk<'a>(cmds: &[Command<'a>]) -> (BTreeSet<Path<'a>>, BTreeMap<(Path<'a>, &'a str), u32>) {
+    let mut cwd = vec![];
+    let mut dirs = BTreeSet::new();
+    let mut files = BTreeMap::new();
+
+    for cmd in cmds {
+        match cmd {
+            Command::Cd(Cd::Root) => cwd.clear(),
+            Command::Cd(Cd::Up) => {
+                cwd.pop();
+            }
+            Command::Cd(Cd::Down(name)) => cwd.push(*name),
+            Command::Ls(inodes) => {
+                dirs.insert(cwd.clone());
+
+                for inode in inodes {
+                    if let Inode::File { size, name } = inode {
+                        files.insert((cwd.clone(), *name), *size);
+                    }
+                }
+            }
+        }
+    }
+
+    (dirs, files)
+}
+
+/// Each listed directory's size, adding up every file below it.
+fn sizes<'a>(cmds: &[Command<'a>]) -> BTreeMap<Path<'a>, u64> {
+    let (dirs, files) = walk(cmds);
+
+    dirs.into_iter()
+        .map(|dir| {
+            let size = files
+                .iter()
+                .filter(|((parent, _), _)| parent.starts_with(&dir))
+                .map(|(_, &size)| u64::from(size))
+                .sum();
+            (dir, size)
+        })
+        .collect()
+}
+
+pub fn part1(cmds: &[Command]) -> u64 {
+    sizes(cmds)
+        .into_values()
+        .filter(|&size| size <= 100_000)
+        .sum()
+}
+
+pub fn part2(cmds: &[Command]) -> Option<u64> {
+    let sizes = sizes(cmds);
+    let free = 70_000_000u64.checked_sub(*sizes.get(&vec![])?)?;
+    let needed = 30_000_000u64.saturating_sub(free);
+
+    sizes.into_values().filter(|&size| size >= needed).min()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Day7, Input};
+    use aoc_core::{oracle::differential, Answer, Part};
+
+    #[test]
+    fn part1_agrees() {
+        differential(&Day7, Part::One, |input: &Input| Some(part1(input).into()));
+    }
+
+    #[test]
+    fn part2_agrees() {
+        differential(&Day7, Part::Two, |input: &Input| {
+            part2(input).map(Answer::from)
+        });
+    }
+}
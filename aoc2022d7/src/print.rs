@@ -0,0 +1,69 @@
+use crate::{Cd, Command, Inode};
+use std::fmt;
+
+impl fmt::Display for Inode<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Inode::Dir(name) => write!(f, "dir {}", name),
+            Inode::File { size, name } => write!(f, "{} {}", size, name),
+        }
+    }
+}
+
+/// A command and, for `ls`, the lines it printed.
+impl fmt::Display for Command<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Command::Cd(Cd::Root) => write!(f, "$ cd /"),
+            Command::Cd(Cd::Up) => write!(f, "$ cd .."),
+            Command::Cd(Cd::Down(name)) => write!(f, "$ cd {}", name),
+            Command::Ls(inodes) => {
+                write!(f, "$ ls")?;
+                for inode in inodes {
+                    write!(f, "\n{}", inode)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Print `cmds` back in the puzzle format.
+pub fn input(cmds: &[Command]) -> String {
+    cmds.iter().map(|cmd| format!("{}\n", cmd)).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parse;
+    use proptest::prelude::*;
+
+    const DIRS: [&str; 4] = ["a", "d", "dir", "qwerty"];
+    const FILES: [&str; 5] = ["b.txt", "c.dat", "f", "g.lst", "i"];
+
+    fn inode() -> impl Strategy<Value = Inode<'static>> {
+        prop_oneof![
+            prop::sample::select(DIRS.to_vec()).prop_map(Inode::Dir),
+            (any::<u32>(), prop::sample::select(FILES.to_vec()))
+                .prop_map(|(size, name)| Inode::File { size, name }),
+        ]
+    }
+
+    fn command() -> impl Strategy<Value = Command<'static>> {
+        prop_oneof![
+            Just(Command::Cd(Cd::Root)),
+            Just(Command::Cd(Cd::Up)),
+            prop::sample::select(DIRS.to_vec()).prop_map(|name| Command::Cd(Cd::Down(name))),
+            prop::collection::vec(inode(), 1..8).prop_map(Command::Ls),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn parse_reverses_print(cmds in prop::collection::vec(command(), 1..30)) {
+            let text = input(&cmds);
+            prop_assert_eq!(parse(&text).unwrap(), cmds);
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use aoc2022d8::Day8;
+use aoc_core::bench::criterion::{criterion_group, criterion_main, Criterion};
+
+fn day8(c: &mut Criterion) {
+    aoc_core::bench::solution(c, &Day8);
+
+    #[cfg(feature = "bytes")]
+    aoc_core::bench::bytes(c, &Day8, aoc2022d8::bytes::parse);
+}
+
+criterion_group!(benches, day8);
+criterion_main!(benches);
@@ -0,0 +1,31 @@
+use crate::{Input, Tree};
+use aoc_core::AocError;
+use nom::{
+    character::complete::line_ending,
+    combinator::{map, verify},
+    multi::{many1, separated_list1},
+    number::complete::u8,
+    IResult,
+};
+
+fn tree(input: &[u8]) -> IResult<&[u8], Tree> {
+    map(verify(u8, u8::is_ascii_digit), |b| Tree {
+        height: (b - b'0').into(),
+    })(input)
+}
+
+fn rows(input: &[u8]) -> IResult<&[u8], Input> {
+    separated_list1(line_ending, many1(tree))(input)
+}
+
+/// Byte level [`crate::parse`].
+pub fn parse(input: &[u8]) -> Result<Input, AocError> {
+    aoc_core::bytes::finish(input, rows)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    aoc_core::bytes_agree!(crate::parse; "30a73\n", "303\n\n255\n");
+}
@@ -0,0 +1,34 @@
+use aoc_core::gen::Rng;
+
+/// A `size` by `size` grid of tree heights.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+
+    (0..size)
+        .map(|_| {
+            let mut row = (0..size)
+                .map(|_| (b'0' + rng.range(0..=9) as u8) as char)
+                .collect::<String>();
+            row.push('\n');
+            row
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{parse, part1, part2};
+
+    #[test]
+    fn generated_inputs_solve() {
+        for seed in 0..20 {
+            let input = input(&mut Rng::new(seed), 12);
+            let trees = parse(&input).unwrap();
+
+            assert_eq!(trees.len(), 12);
+            assert!(part1(&trees) >= 4 * 11);
+            part2(&trees);
+        }
+    }
+}
@@ -1,139 +1,235 @@
-use clap::Parser;
+#[cfg(feature = "bytes")]
+pub mod bytes;
+pub mod gen;
+pub mod lint;
+pub mod oracle;
+pub mod print;
+
+use aoc_core::{
+    gen::Rng, lint::Lint, shrink::Split, Answer, AocError, ParseResult, Solution, Span,
+};
 use nom::{
-    branch::alt,
-    bytes::complete::{is_a, tag},
-    character::complete::{self, alpha1, line_ending, not_line_ending, one_of, space1},
+    character::complete::{line_ending, one_of},
     combinator::map,
+    error::context,
     multi::{many1, separated_list1},
-    sequence::{separated_pair, terminated, tuple},
-    IResult,
 };
+use serde::Serialize;
 
-#[derive(Parser, Debug)]
-#[command(
-    name = "aoc2022d8",
-    author = "Felipe Balbi <felipe@balbi.sh>",
-    about = "Advent of Code 2022 Day 8",
-    version = "0.1.0"
-)]
-pub struct Cli {
-    #[arg(short = '1', long = "part1", help = "Run part 1.")]
-    part1: bool,
-
-    #[arg(short = '2', long = "part2", help = "Run part 2.")]
-    part2: bool,
-}
+/// The parsed puzzle input.
+pub type Input = Vec<Vec<Tree>>;
 
-impl Cli {
-    pub fn run(&self, input: &str) {
-        if self.part1 {
-            println!("Part 1: {}", part1(input));
-        }
+pub struct Day8;
 
-        if self.part2 {
-            println!("Part 2: {}", part2(input));
-        }
+impl Solution for Day8 {
+    type Input<'a> = Input;
+
+    fn name(&self) -> &'static str {
+        "aoc2022d8"
+    }
+
+    fn year(&self) -> u32 {
+        2022
+    }
+
+    fn day(&self) -> u32 {
+        8
+    }
+
+    fn manifest_dir(&self) -> &'static str {
+        env!("CARGO_MANIFEST_DIR")
+    }
+
+    fn parse_span<'a>(&self, input: Span<'a>) -> ParseResult<'a, Self::Input<'a>> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input).into())
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        Some(gen::input(rng, size))
+    }
+
+    fn splits(&self, input: &str) -> Vec<Split> {
+        let mut splits = vec![Split::lines(input)];
+        splits.extend(columns(input));
+        splits
+    }
+
+    fn print(&self, input: &Self::Input<'_>) -> Option<String> {
+        Some(print::input(input))
     }
+
+    fn lint(&self, input: &str) -> Vec<Lint> {
+        lint::lints(input)
+    }
+}
+
+/// Parse the grid of tree heights, one row per line.
+pub fn parse(input: &str) -> Result<Input, AocError> {
+    Day8.parse(input)
 }
 
-#[derive(Debug, PartialOrd, PartialEq)]
-struct Tree {
-    height: u32,
+#[derive(Debug, Clone, PartialOrd, PartialEq, Serialize)]
+pub struct Tree {
+    pub height: u32,
 }
 
-fn tree(input: &str) -> IResult<&str, Tree> {
+fn tree(input: Span<'_>) -> ParseResult<'_, Tree> {
     map(one_of("0123456789"), |c| Tree {
         height: c.to_digit(10).unwrap(),
     })(input)
 }
 
-fn row(input: &str) -> IResult<&str, Vec<Tree>> {
-    many1(tree)(input)
+fn row(input: Span<'_>) -> ParseResult<'_, Vec<Tree>> {
+    context("a row of tree heights", many1(tree))(input)
 }
 
-fn rows(input: &str) -> IResult<&str, Vec<Vec<Tree>>> {
+fn rows(input: Span<'_>) -> ParseResult<'_, Vec<Vec<Tree>>> {
     separated_list1(line_ending, row)(input)
 }
 
-fn parse_input_part1(input: &str) -> IResult<&str, Vec<Vec<Tree>>> {
+fn parse_input(input: Span<'_>) -> ParseResult<'_, Vec<Vec<Tree>>> {
     rows(input)
 }
 
-fn parse_input_part2(input: &str) -> IResult<&str, Vec<&str>> {
-    todo!()
+/// The columns of a rectangular grid, as a [`Split`] for shrinking.
+fn columns(input: &str) -> Option<Split> {
+    let rows = input.lines().map(str::as_bytes).collect::<Vec<_>>();
+    let width = rows.first()?.len();
+
+    if rows.iter().any(|row| row.len() != width) {
+        return None;
+    }
+
+    let pieces = (0..width)
+        .map(|j| rows.iter().map(|row| row[j] as char).collect())
+        .collect();
+    let height = rows.len();
+
+    Some(Split {
+        pieces,
+        join: Box::new(move |columns: &[String]| {
+            (0..height)
+                .map(|i| {
+                    let mut row = columns
+                        .iter()
+                        .map(|column| column.as_bytes()[i] as char)
+                        .collect::<String>();
+                    row.push('\n');
+                    row
+                })
+                .collect()
+        }),
+    })
 }
 
-fn part1(input: &str) -> u32 {
-    let (_, trees) = parse_input_part1(input).unwrap();
-    let mut visible = vec![];
+/// Walk from `(i, j)` towards an edge, one `(di, dj)` step at a time,
+/// yielding every tree along the way.
+fn line_of_sight(
+    trees: &[Vec<Tree>],
+    i: usize,
+    j: usize,
+    (di, dj): (isize, isize),
+) -> impl Iterator<Item = &Tree> {
+    (1..)
+        .map(move |k| {
+            let ii = i.checked_add_signed(di * k)?;
+            let jj = j.checked_add_signed(dj * k)?;
+            trees.get(ii)?.get(jj)
+        })
+        .take_while(Option::is_some)
+        .flatten()
+}
 
-    for (i, row) in trees.iter().enumerate() {
-        for (j, t) in row.iter().enumerate() {
-            if i == 0 || i == trees.len() - 1 {
-                visible.push(true);
-                continue;
-            }
+const DIRECTIONS: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+pub fn part1(trees: &[Vec<Tree>]) -> usize {
+    trees
+        .iter()
+        .enumerate()
+        .flat_map(|(i, row)| row.iter().enumerate().map(move |(j, t)| (i, j, t)))
+        .filter(|(i, j, t)| {
+            DIRECTIONS
+                .iter()
+                .any(|&dir| line_of_sight(trees, *i, *j, dir).all(|other| other < t))
+        })
+        .count()
+}
 
-            if j == 0 || j == row.len() - 1 {
-                visible.push(true);
-                continue;
-            }
+fn viewing_distance(trees: &[Vec<Tree>], i: usize, j: usize, dir: (isize, isize)) -> usize {
+    let t = &trees[i][j];
+    let mut distance = 0;
 
-            if taller_than_neighbors(&t, i, j, &trees) {
-                visible.push(true);
-                continue;
-            }
+    for other in line_of_sight(trees, i, j, dir) {
+        distance += 1;
 
-            visible.push(false);
+        if other >= t {
+            break;
         }
     }
 
-    dbg!(visible.len());
-    dbg!(visible.iter().filter(|v| **v).count());
-
-    420
+    distance
 }
 
-/// this is wrong. I need to check that there is a path from the
-/// current tree to any of the four edges. Perhaps a good time to
-/// learn petgraph?
-fn taller_than_neighbors(t: &Tree, i: usize, j: usize, trees: &Vec<Vec<Tree>>) -> bool {
-    let north = &trees[i - 1][j];
-    let south = &trees[i + 1][j];
-    let east = &trees[i][j + 1];
-    let west = &trees[i][j - 1];
-
-    t > north || t > south || t > east || t > west
-}
-
-fn part2(input: &str) -> u32 {
-    420
+pub fn part2(trees: &[Vec<Tree>]) -> usize {
+    trees
+        .iter()
+        .enumerate()
+        .flat_map(|(i, row)| (0..row.len()).map(move |j| (i, j)))
+        .map(|(i, j)| {
+            DIRECTIONS
+                .iter()
+                .map(|&dir| viewing_distance(trees, i, j, dir))
+                .product::<usize>()
+        })
+        .max()
+        .unwrap_or(0)
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
 
+    aoc_core::verify_answers!(Day8);
+    aoc_core::verify_examples!(Day8);
+
+    #[test]
+    fn lone_tree_is_visible() {
+        let trees = vec![vec![Tree { height: 9 }]];
+
+        assert_eq!(part1(&trees), 1);
+        assert_eq!(part2(&trees), 0);
+    }
+
     #[test]
-    fn part1_works() {
-        let input = "30373
-25512
-65332
-33549
-35390";
+    fn ragged_rows_do_not_panic() {
+        let input = "111\n11111\n111\n";
 
-        assert_eq!(part1(input), 21);
+        assert!(Day8.part1(&Day8.parse(input).unwrap()).is_ok());
     }
 
     #[test]
-    #[ignore]
-    fn part2_works() {
-        let input = "30373
-25512
-65332
-33549
-35390";
-
-        assert_eq!(part2(input), 42);
+    fn columns_join_back() {
+        let split = columns("303\n255\n").unwrap();
+
+        assert_eq!(split.pieces, ["32", "05", "35"]);
+        assert_eq!((split.join)(&split.pieces[1..]), "03\n55\n");
+        assert!(columns("30\n255\n").is_none());
+    }
+
+    #[test]
+    fn splits_are_rows_then_columns() {
+        let splits = Day8.splits("303\n255\n");
+
+        assert_eq!(splits[0].pieces, ["303", "255"]);
+        assert_eq!(splits[1].pieces, ["32", "05", "35"]);
+        assert_eq!(Day8.splits("30\n255\n").len(), 1);
     }
 }
@@ -0,0 +1,33 @@
+use crate::row;
+use aoc_core::lint::{Lint, Severity};
+use aoc_core::{recover, Span};
+
+pub fn lints(input: &str) -> Vec<Lint> {
+    let (rows, _) = recover::lines(Span::new(input), row);
+    let width = rows.first().map_or(0, |(_, row)| row.len());
+
+    rows.iter()
+        .filter(|(_, row)| row.len() != width)
+        .map(|(span, row)| {
+            Lint::at(
+                Severity::Warning,
+                "ragged-row",
+                format!("{} trees, but the first row has {}", row.len(), width),
+                span,
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn ragged_rows_are_flagged() {
+        let lints = lints("303\n25\n653\n");
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].location.as_ref().unwrap().line, 2);
+    }
+}
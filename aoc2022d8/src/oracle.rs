@@ -0,0 +1,78 @@
+//! Deliberately naive reference solutions, for differential tests. The
+//! grid is assumed to be rectangular.
+
+use crate::Tree;
+
+/// How many trees can be seen from a tree of height `h` before one at
+/// least as tall blocks the view.
+fn distance(h: u32, heights: impl Iterator<Item = u32>) -> usize {
+    let mut seen = 0;
+
+    for other in heights {
+        seen += 1;
+        if other >= h {
+            break;
+        }
+    }
+
+    seen
+}
+
+pub fn part1(trees: &[Vec<Tree>]) -> usize {
+    let (rows, cols) = (trees.len(), trees[0].len());
+    let mut visible = 0;
+
+    for i in 0..rows {
+        for j in 0..cols {
+            let h = trees[i][j].height;
+
+            let up = (0..i).all(|k| trees[k][j].height < h);
+            let down = (i + 1..rows).all(|k| trees[k][j].height < h);
+            let left = (0..j).all(|k| trees[i][k].height < h);
+            let right = (j + 1..cols).all(|k| trees[i][k].height < h);
+
+            if up || down || left || right {
+                visible += 1;
+            }
+        }
+    }
+
+    visible
+}
+
+pub fn part2(trees: &[Vec<Tree>]) -> usize {
+    let (rows, cols) = (trees.len(), trees[0].len());
+    let mut best = 0;
+
+    for i in 0..rows {
+        for j in 0..cols {
+            let h = trees[i][j].height;
+
+            let up = distance(h, (0..i).rev().map(|k| trees[k][j].height));
+            let down = distance(h, (i + 1..rows).map(|k| trees[k][j].height));
+            let left = distance(h, (0..j).rev().map(|k| trees[i][k].height));
+            let right = distance(h, (j + 1..cols).map(|k| trees[i][k].height));
+
+            best = best.max(up * down * left * right);
+        }
+    }
+
+    best
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Day8, Input};
+    use aoc_core::{oracle::differential, Part};
+
+    #[test]
+    fn part1_agrees() {
+        differential(&Day8, Part::One, |input: &Input| Some(part1(input).into()));
+    }
+
+    #[test]
+    fn part2_agrees() {
+        differential(&Day8, Part::Two, |input: &Input| Some(part2(input).into()));
+    }
+}
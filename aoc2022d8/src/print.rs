@@ -0,0 +1,39 @@
+use crate::Tree;
+use std::fmt;
+
+impl fmt::Display for Tree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.height)
+    }
+}
+
+/// Print `trees` back in the puzzle format.
+pub fn input(trees: &[Vec<Tree>]) -> String {
+    trees
+        .iter()
+        .map(|row| {
+            let mut line = row.iter().map(Tree::to_string).collect::<String>();
+            line.push('\n');
+            line
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parse;
+    use proptest::prelude::*;
+
+    fn row() -> impl Strategy<Value = Vec<Tree>> {
+        prop::collection::vec((0..=9u32).prop_map(|height| Tree { height }), 1..20)
+    }
+
+    proptest! {
+        #[test]
+        fn parse_reverses_print(trees in prop::collection::vec(row(), 1..20)) {
+            let text = input(&trees);
+            prop_assert_eq!(parse(&text).unwrap(), trees);
+        }
+    }
+}
@@ -0,0 +1,22 @@
+#![no_main]
+
+use aoc2022d3::Day3;
+use aoc_core::{Options, Part, Runner, Solution};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        for recover in [false, true] {
+            let options = Options {
+                recover,
+                ..Options::default()
+            };
+            let _ = Day3.run(input, &[Part::One, Part::Two], &options);
+        }
+    }
+
+    if let Ok(input) = aoc2022d3::bytes::parse(data) {
+        let _ = Day3.part1(&input);
+        let _ = Day3.part2(&input);
+    }
+});